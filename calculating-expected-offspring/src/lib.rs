@@ -2,7 +2,9 @@ use std::ops::Deref;
 
 use florist_core::{Error, SingleGenePopulation};
 use florist_inputs::U32List;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use itertools::Itertools;
+use rand::Rng;
 
 pub struct CalculatingExpectedOffspring;
 
@@ -11,23 +13,30 @@ impl Problem for CalculatingExpectedOffspring {
     type Input = U32List;
     type Output = f64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "1 0 0 1 0 1",
+        output: "3.5",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let pop = SingleGenePopulation::try_from(input.deref())?;
         Ok(pop.expected_dominant_offspring(2))
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let max = size.clamp(1, 20_000) as u32;
+        Some((0..6).map(|_| rng.gen_range(0..=max)).join(" "))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn expected_offspring() {
-        let raw = "1 0 0 1 0 1";
-        let input = U32List::from_str(raw).unwrap();
-        let output = CalculatingExpectedOffspring::solve(input).unwrap();
-        assert_eq!(output, 3.5);
+    fn sample() {
+        assert_sample::<CalculatingExpectedOffspring>();
     }
 }
@@ -1,5 +1,6 @@
-use florist_core::sequence::ProteinSequence;
-use florist_plumbing::Problem;
+use florist_core::{Protein, ProteinSequence};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct CalculatingProteinMass;
 
@@ -8,21 +9,28 @@ impl Problem for CalculatingProteinMass {
     type Input = ProteinSequence;
     type Output = f64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "SKADYEK",
+        output: "821.392",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         Ok(input.monoisotopic_mass())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        Some(florist_gen::sequence::<Protein, _>(rng, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
-    use std::str::FromStr;
 
     #[test]
-    fn protein_mass() {
-        let raw = "SKADYEK";
-        let input = ProteinSequence::from_str(raw).unwrap();
-        let output = CalculatingProteinMass::solve(input).unwrap();
-        assert!(output - 821.3919199999999 < f64::EPSILON);
+    fn sample() {
+        assert_sample::<CalculatingProteinMass>();
     }
 }
@@ -1,5 +1,6 @@
-use florist_core::{DNASequence, Error};
-use florist_plumbing::Problem;
+use florist_core::{DNASequence, Dna, Error};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct ComplementingAStrandOfDna;
 
@@ -8,20 +9,28 @@ impl Problem for ComplementingAStrandOfDna {
     type Input = DNASequence;
     type Output = DNASequence;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "AAAACCCGGT",
+        output: "ACCGGGTTTT",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         Ok(input.reverse_complement())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        Some(florist_gen::sequence::<Dna, _>(rng, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
-    use std::str::FromStr;
 
     #[test]
-    fn complement() {
-        let input = DNASequence::from_str("AAAACCCGGT").expect("Failed to parse");
-        let output = ComplementingAStrandOfDna::solve(input).expect("Failed to solve");
-        assert_eq!(output, DNASequence::from_str("ACCGGGTTTT").unwrap());
+    fn sample() {
+        assert_sample::<ComplementingAStrandOfDna>();
     }
 }
@@ -1,13 +1,17 @@
 use std::{fmt::Display, str::FromStr};
 
 use anyhow::{anyhow, Result};
-use florist_core::{DNASequence, Error, GCContent};
+use florist_core::{DNASequence, Dna, Error, GCContent};
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Output {
     description: String,
+
+    /// The fraction of G and C, which the text output shows as a percentage.
     gc_content: f64,
 }
 
@@ -25,6 +29,20 @@ impl Problem for ComputingGcContent {
     type Input = MultiFasta;
     type Output = Output;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_6404
+CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCC
+TCCCACTAATAATTCTGAGG
+>Rosalind_5959
+CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCCAGGCGCTCCGCCGAAGGTCT
+ATATCCATTTGTCAGCAGACACGC
+>Rosalind_0808
+CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGAC
+TGGGAACCTGCGGGCAGTAGGTGGAAT",
+        output: "Rosalind_0808
+60.919540",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let content = input
             .iter()
@@ -42,29 +60,21 @@ impl Problem for ComputingGcContent {
             gc_content: *gc,
         })
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let count = rng.gen_range(1..=10);
+        Some(florist_gen::random_fasta::<Dna, _>(rng, count, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn gc_content() {
-        let data = "
->Rosalind_6404
-CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCC
-TCCCACTAATAATTCTGAGG
->Rosalind_5959
-CCATCGGTAGCGCATCCTTAGTCCAATTAAGTCCCTATCCAGGCGCTCCGCCGAAGGTCT
-ATATCCATTTGTCAGCAGACACGC
->Rosalind_0808
-CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGAC
-TGGGAACCTGCGGGCAGTAGGTGGAAT
-";
-        let input = MultiFasta::from_str(data).unwrap();
-        let output = ComputingGcContent::solve(input).unwrap();
-
-        assert_eq!(output.description, "Rosalind_0808".to_string());
-        assert!(output.gc_content - 0.6091954022988506 <= f64::EPSILON);
+    fn sample() {
+        assert_sample::<ComputingGcContent>();
     }
 }
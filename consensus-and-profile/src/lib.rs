@@ -1,13 +1,36 @@
-use florist_core::{Consensus, DNASequence, Error};
+use florist_core::{Consensus, DNASequence, Dna, Error};
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct ConsensusAndProfile;
 
 impl Problem for ConsensusAndProfile {
     type Error = Error;
     type Input = MultiFasta;
-    type Output = Consensus<DNASequence>;
+    type Output = Consensus<Dna>;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_1
+ATCCAGCT
+>Rosalind_2
+GGGCAACT
+>Rosalind_3
+ATGGATCT
+>Rosalind_4
+AAGCAACC
+>Rosalind_5
+TTGGAACT
+>Rosalind_6
+ATGCCATT
+>Rosalind_7
+ATGGCACT",
+        output: "ATGCAACT
+A: 5 1 0 0 5 5 0 0
+C: 0 0 1 4 2 0 6 1
+G: 1 1 6 3 0 1 0 0
+T: 1 5 0 0 0 1 1 6",
+    });
 
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let seqs = input
@@ -18,39 +41,21 @@ impl Problem for ConsensusAndProfile {
 
         Consensus::try_from_iter(seqs.iter())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let count = rng.gen_range(1..=10);
+        Some(florist_gen::random_fasta::<Dna, _>(rng, count, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn consensus() {
-        let raw = ">Rosalind_1
-ATCCAGCT
->Rosalind_2
-GGGCAACT
->Rosalind_3
-ATGGATCT
->Rosalind_4
-AAGCAACC
->Rosalind_5
-TTGGAACT
->Rosalind_6
-ATGCCATT
->Rosalind_7
-ATGGCACT";
-        let input = MultiFasta::from_str(raw).unwrap();
-        let output = ConsensusAndProfile::solve(input).unwrap();
-        let expected = "ATGCAACT
-A: 5 1 0 0 5 5 0 0
-C: 0 0 1 4 2 0 6 1
-G: 1 1 6 3 0 1 0 0
-T: 1 5 0 0 0 1 1 6"
-            .to_string();
-
-        assert_eq!(output.to_string(), expected);
+    fn sample() {
+        assert_sample::<ConsensusAndProfile>();
     }
 }
@@ -1,9 +1,11 @@
 use std::fmt::Display;
 
-use florist_core::{DNASequence, Error};
-use florist_plumbing::Problem;
+use florist_core::{DNASequence, Dna, Error};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize)]
 pub struct NucleotideCounts {
     a: usize,
     c: usize,
@@ -24,6 +26,11 @@ impl Problem for CountingDnaNucleotides {
     type Input = DNASequence;
     type Output = NucleotideCounts;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "AGCTTTTCATTCTGACTGCAACGGGCAATATGTCTCTGTGTGGATTAAAAAAAGAGTGTCTGATAGCAGC",
+        output: "20 12 17 21",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut counts = NucleotideCounts::default();
         input.chars().for_each(|ch| match ch {
@@ -36,30 +43,20 @@ impl Problem for CountingDnaNucleotides {
 
         Ok(counts)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        Some(florist_gen::sequence::<Dna, _>(rng, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
-    use std::str::FromStr;
 
     #[test]
-    fn counting() {
-        let input = DNASequence::from_str(
-            "AGCTTTTCATTCTGACTGCAACGGGCAATATGTCTCTGTGTGGATTAAAAAAAGAGTGTCTGATAGCAGC",
-        )
-        .expect("Invalid input");
-
-        let output = CountingDnaNucleotides::solve(input).expect("Failed to solve");
-
-        assert_eq!(
-            output,
-            NucleotideCounts {
-                a: 20,
-                c: 12,
-                g: 17,
-                t: 21,
-            }
-        )
+    fn sample() {
+        assert_sample::<CountingDnaNucleotides>();
     }
 }
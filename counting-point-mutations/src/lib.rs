@@ -2,7 +2,8 @@ use std::str::FromStr;
 
 use anyhow::bail;
 use florist_core::{DNASequence, Error, HammingDistance};
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SequencePair {
@@ -40,23 +41,32 @@ impl Problem for CountingPointMutations {
     type Input = SequencePair;
     type Output = u64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "GAGCCTACTAACGGGAT
+CATCGTAATGACGGCCT",
+        output: "7",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.hamming_distance()
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: DNASequence = florist_gen::sequence(rng, size);
+        let rate = rng.gen();
+        let second = florist_gen::mutate(rng, &first, rate);
+        Some(format!("{}\n{}", first, second))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn counting_mutations() {
-        let raw = "
-GAGCCTACTAACGGGAT
-CATCGTAATGACGGCCT
-";
-        let input = SequencePair::from_str(raw).unwrap();
-        let output = CountingPointMutations::solve(input).unwrap();
-        assert_eq!(output, 7);
+    fn sample() {
+        assert_sample::<CountingPointMutations>();
     }
 }
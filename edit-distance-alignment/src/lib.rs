@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::Aligner;
+use florist_core::ProteinSequence;
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The edit distance and an alignment achieving it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct EditAlignment {
+    pub distance: u64,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for EditAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}\n{}", self.distance, self.first, self.second)
+    }
+}
+
+pub struct EditDistanceAlignment;
+
+impl Problem for EditDistanceAlignment {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = EditAlignment;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_43
+PRETTY
+>Rosalind_97
+PRTTEIN",
+        output: "4
+PRETTY--
+PR-TTEIN",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: ProteinSequence = input[0].1.parse()?;
+        let second: ProteinSequence = input[1].1.parse()?;
+        let alignment = Aligner::levenshtein().align(&first, &second);
+
+        Ok(EditAlignment {
+            distance: alignment.edits() as u64,
+            first: alignment.first,
+            second: alignment.second,
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: ProteinSequence = florist_gen::sequence(rng, size.min(1000));
+        let rate = rng.gen_range(0.0..0.5);
+        let second = florist_gen::evolve(rng, &first, rate);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::GAP;
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<EditDistanceAlignment>();
+    }
+
+    #[test]
+    fn rows_realise_the_distance() {
+        let sample = EditDistanceAlignment::SAMPLE.unwrap();
+        let input = EditDistanceAlignment::input_from(sample.input).unwrap();
+        let output = EditDistanceAlignment::solve(input.clone()).unwrap();
+
+        // Rosalind accepts any alignment with the fewest edits
+        assert_eq!(output.first.replace(GAP, ""), input[0].1);
+        assert_eq!(output.second.replace(GAP, ""), input[1].1);
+        let score = Aligner::levenshtein().score_rows(&output.first, &output.second);
+        assert_eq!(-score as u64, output.distance);
+    }
+}
@@ -0,0 +1,64 @@
+use anyhow::bail;
+use florist_align::edit_distance;
+use florist_core::ProteinSequence;
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
+
+pub struct EditDistance;
+
+impl Problem for EditDistance {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = u64;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_39
+PLEASANTLY
+>Rosalind_11
+MEANLY",
+        output: "5",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: ProteinSequence = input[0].1.parse()?;
+        let second: ProteinSequence = input[1].1.parse()?;
+
+        Ok(edit_distance(&first, &second))
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: ProteinSequence = florist_gen::sequence(rng, size.min(1000));
+        let rate = rng.gen_range(0.0..0.5);
+        let second = florist_gen::evolve(rng, &first, rate);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<EditDistance>();
+    }
+
+    #[test]
+    fn only_insertions() {
+        let raw = ">Rosalind_1
+ACDE
+>Rosalind_2
+WACDEWW";
+        let input = OrderedMultiFasta::from_str(raw).unwrap();
+        assert_eq!(EditDistance::solve(input).unwrap(), 3);
+    }
+}
@@ -4,9 +4,11 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use florist_plumbing::Problem;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
 pub struct Permutation(Vec<u32>);
 
 impl Deref for Permutation {
@@ -23,7 +25,7 @@ impl Display for Permutation {
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
 pub struct Permutations(Vec<Permutation>);
 
 impl Deref for Permutations {
@@ -53,24 +55,41 @@ impl Problem for EnumeratingGeneOrders {
     type Input = u32;
     type Output = Permutations;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "3",
+        output: "6
+1 2 3
+1 3 2
+2 1 3
+2 3 1
+3 1 2
+3 2 1",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let raw = (1..=input)
             .permutations(input as usize)
-            .map(|p| Permutation(p))
+            .map(Permutation)
             .collect::<Vec<_>>();
 
         Ok(Permutations(raw))
     }
+
+    fn generate<R: Rng + ?Sized>(_rng: &mut R, size: usize) -> Option<String> {
+        Some(size.clamp(1, 7).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn finding_permutations() {
-        let input = 3;
-        let output = EnumeratingGeneOrders::solve(input).unwrap();
-        assert_eq!(output.len(), 6);
+    fn sample() {
+        assert_sample::<EnumeratingGeneOrders>();
     }
 }
@@ -4,10 +4,12 @@ use std::{
 };
 
 use florist_core::Error;
-use florist_plumbing::Problem;
+use florist_plumbing::{OutputOrder, Problem, Sample};
 use itertools::Itertools;
+use rand::Rng;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
 pub struct Output(Vec<Vec<i32>>);
 
 impl Deref for Output {
@@ -42,6 +44,21 @@ impl Problem for EnumeratingOrientedGeneOrderings {
     type Input = u16;
     type Output = Output;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "2",
+        output: "8
+-1 -2
+-1 2
+1 -2
+1 2
+-2 -1
+-2 1
+2 -1
+2 1",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let vals: Vec<Vec<i32>> = (1_i32..=(input as i32))
             .permutations(input as usize)
@@ -50,33 +67,20 @@ impl Problem for EnumeratingOrientedGeneOrderings {
 
         Ok(Output(vals))
     }
+
+    fn generate<R: Rng + ?Sized>(_rng: &mut R, size: usize) -> Option<String> {
+        Some(size.clamp(1, 6).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn example() {
-        let raw = "2";
-        let input = u16::from_str(raw).expect("Failed to parse input");
-        let mut output = EnumeratingOrientedGeneOrderings::solve(input).expect("Failed to solve");
-        let mut expected = Output(vec![
-            vec![-1, -2],
-            vec![-1, 2],
-            vec![1, -2],
-            vec![1, 2],
-            vec![-2, -1],
-            vec![-2, 1],
-            vec![2, -1],
-            vec![2, 1],
-        ]);
-
-        output.sort();
-        expected.sort();
-
-        assert_eq!(output, expected);
+    fn sample() {
+        assert_sample::<EnumeratingOrientedGeneOrderings>();
     }
 }
@@ -1,11 +1,13 @@
 use std::fmt::Display;
 
 use anyhow::bail;
-use florist_core::Motif;
+use florist_core::{DNASequence, Dna, FmIndex};
 use florist_inputs::DNASequenceList;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize)]
 pub struct MotifLocations(Vec<usize>);
 
 impl From<Vec<usize>> for MotifLocations {
@@ -32,33 +34,50 @@ impl Problem for FindingAMotifInDna {
     type Input = DNASequenceList;
     type Output = MotifLocations;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "GATATATGCATATACTT
+ATAT",
+        output: "2 4 10",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         if input.len() != 2 {
             bail!("Input is not the right number (2) of sequences");
         }
 
-        Ok(input[0]
-            .motif_lcoations(&input[1])
+        Ok(FmIndex::new(&input[0])
+            .locate(&input[1])
             .iter()
             .map(|v| v + 1)
             .collect::<Vec<_>>()
             .into())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let seq: DNASequence = florist_gen::sequence(rng, size);
+        let len = rng.gen_range(1..=seq.len().min(10));
+
+        // take the motif from the sequence half the time so there is
+        // something to find
+        let motif = if rng.gen() {
+            let start = rng.gen_range(0..=(seq.len() - len));
+            seq[start..(start + len)].to_string()
+        } else {
+            florist_gen::sequence::<Dna, _>(rng, len).to_string()
+        };
+
+        Some(format!("{}\n{}", seq, motif))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn finding_motif() {
-        let raw = "GATATATGCATATACTT
-ATAT";
-
-        let input = DNASequenceList::from_str(raw).unwrap();
-        let output = FindingAMotifInDna::solve(input).unwrap();
-        assert_eq!(output.to_string(), "2 4 10")
+    fn sample() {
+        assert_sample::<FindingAMotifInDna>();
     }
 }
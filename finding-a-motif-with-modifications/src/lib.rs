@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::{Aligner, GapPenalty, MatchMismatch, Mode};
+use florist_core::DNASequence;
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The score of the best fitting alignment and the alignment itself.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct FittingAlignment {
+    pub score: i32,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for FittingAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}\n{}", self.score, self.first, self.second)
+    }
+}
+
+pub struct FindingAMotifWithModifications;
+
+impl FindingAMotifWithModifications {
+    /// Matches score 1, and mismatches and gapped positions -1.
+    fn aligner() -> Aligner<MatchMismatch> {
+        Aligner::new(
+            Mode::Fitting,
+            MatchMismatch::new(1, -1),
+            GapPenalty::linear(1),
+        )
+    }
+}
+
+impl Problem for FindingAMotifWithModifications {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = FittingAlignment;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_54
+GCAAACCATAAGCCCTACGTGCCGCCTGTTTAAACTCGCGAACTGAATCTTCTGCTTCACGGTGAAAGTACCACAATGGTATCACACCCCAAGGAAAC
+>Rosalind_46
+GCCGTCAGGCTGGTGTCCG",
+        output: "5
+ACCATAAGCCCTACGTG-CCG
+GCCGTCAGGC-TG-GTGTCCG",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: DNASequence = input[0].1.parse()?;
+        let motif: DNASequence = input[1].1.parse()?;
+        let alignment = Self::aligner().align(&first, &motif);
+
+        Ok(FittingAlignment {
+            score: alignment.score,
+            first: alignment.first,
+            second: alignment.second,
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let seq: DNASequence = florist_gen::sequence(rng, size.min(10_000));
+
+        // the motif is a modified part of the sequence, of at most 1 kbp
+        let len = rng.gen_range(1..=seq.len().min(1000));
+        let start = rng.gen_range(0..=(seq.len() - len));
+        let rate = rng.gen_range(0.0..0.3);
+        let motif = florist_gen::evolve(rng, &seq.subsequence_unchecked(start, start + len), rate);
+
+        Some(florist_gen::fasta(rng, [seq, motif]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::GAP;
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<FindingAMotifWithModifications>();
+    }
+
+    #[test]
+    fn rows_score_as_reported() {
+        let sample = FindingAMotifWithModifications::SAMPLE.unwrap();
+        let input = FindingAMotifWithModifications::input_from(sample.input).unwrap();
+        let output = FindingAMotifWithModifications::solve(input.clone()).unwrap();
+
+        // Rosalind accepts any alignment as good
+        assert!(input[0].1.contains(&output.first.replace(GAP, "")));
+        assert_eq!(output.second.replace(GAP, ""), input[1].1);
+        let aligner = FindingAMotifWithModifications::aligner();
+        assert_eq!(
+            aligner.score_rows(&output.first, &output.second),
+            output.score
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! The result of aligning two sequences.
+use std::{fmt::Display, ops::Range};
+
+use serde::Serialize;
+
+pub use florist_core::GAP;
+
+/// One column of an alignment, read as the edit turning the first sequence
+/// into the second.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize)]
+pub enum Operation {
+    /// The same symbol in both.
+    Match,
+
+    /// Different symbols in each.
+    Mismatch,
+
+    /// A symbol only in the second sequence.
+    Insertion,
+
+    /// A symbol only in the first sequence.
+    Deletion,
+}
+
+impl Operation {
+    /// The code for the operation in an extended CIGAR string.
+    pub fn code(&self) -> char {
+        match self {
+            Self::Match => '=',
+            Self::Mismatch => 'X',
+            Self::Insertion => 'I',
+            Self::Deletion => 'D',
+        }
+    }
+}
+
+/// The operations of an alignment, run-length encoded.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, Serialize)]
+pub struct Cigar(Vec<(Operation, usize)>);
+
+impl Cigar {
+    /// Appends an operation, extending the last run if it is the same.
+    pub fn push(&mut self, op: Operation) {
+        match self.0.last_mut() {
+            Some((last, count)) if *last == op => *count += 1,
+            _ => self.0.push((op, 1)),
+        }
+    }
+
+    /// Each operation with how many times in a row it occurs.
+    pub fn runs(&self) -> &[(Operation, usize)] {
+        &self.0
+    }
+
+    /// Every operation, one per column.
+    pub fn operations(&self) -> impl Iterator<Item = Operation> + '_ {
+        self.0
+            .iter()
+            .flat_map(|(op, count)| std::iter::repeat_n(*op, *count))
+    }
+}
+
+impl FromIterator<Operation> for Cigar {
+    fn from_iter<T: IntoIterator<Item = Operation>>(iter: T) -> Self {
+        let mut cigar = Self::default();
+        for op in iter {
+            cigar.push(op);
+        }
+
+        cigar
+    }
+}
+
+/// Displays as an extended CIGAR string, e.g. `3=1X2I`.
+impl Display for Cigar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (op, count) in &self.0 {
+            write!(f, "{}{}", count, op.code())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An alignment of part or all of two sequences.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Alignment {
+    pub score: i32,
+
+    /// The part of the first sequence that is aligned.
+    pub first_range: Range<usize>,
+
+    /// The part of the second sequence that is aligned.
+    pub second_range: Range<usize>,
+
+    /// The aligned part of the first sequence, with gaps.
+    pub first: String,
+
+    /// The aligned part of the second sequence, with gaps.
+    pub second: String,
+
+    pub cigar: Cigar,
+}
+
+impl Alignment {
+    /// The number of columns.
+    pub fn len(&self) -> usize {
+        self.first.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_empty()
+    }
+
+    /// How many columns do not match: the edit distance for a global
+    /// alignment with unit costs.
+    pub fn edits(&self) -> usize {
+        self.cigar
+            .runs()
+            .iter()
+            .filter(|(op, _)| *op != Operation::Match)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// The fraction of columns that match.
+    pub fn identity(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        (self.len() - self.edits()) as f64 / self.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_runs() {
+        use Operation::*;
+
+        let cigar: Cigar = [Match, Match, Mismatch, Insertion, Insertion, Match]
+            .into_iter()
+            .collect();
+        assert_eq!(cigar.to_string(), "2=1X2I1=");
+        assert_eq!(cigar.operations().count(), 6);
+        assert_eq!(Cigar::default().to_string(), "");
+    }
+}
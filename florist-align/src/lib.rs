@@ -0,0 +1,22 @@
+//! Pairwise and multiple alignment of sequences.
+//!
+//! An [Aligner] combines a [Mode], saying which parts of the sequences must
+//! be aligned, with a [Scoring] scheme for aligned symbols and a
+//! [GapPenalty]. It works on any [Sequence](florist_core::Sequence), so DNA
+//! and proteins are aligned the same way.
+//!
+//! [Aligner::align] keeps a byte per cell of the table, so for long sequences
+//! [Aligner::align_linear] finds an equally good alignment in linear space,
+//! and [Aligner::align_banded] one that stays near the diagonal.
+//!
+//! A [MultipleAligner] aligns more than two sequences into gapped rows.
+pub mod alignment;
+pub mod linear;
+pub mod multiple;
+pub mod pairwise;
+pub mod scoring;
+
+pub use alignment::{Alignment, Cigar, Operation, GAP};
+pub use multiple::{MultipleAligner, MultipleAlignment};
+pub use pairwise::{edit_distance, Aligner, Mode};
+pub use scoring::{GapPenalty, MatchMismatch, Scoring};
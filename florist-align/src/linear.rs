@@ -0,0 +1,389 @@
+//! Alignment in memory proportional to the lengths of the sequences, for
+//! sequences too long to keep [Aligner::align]'s traceback for.
+//!
+//! This is Hirschberg's divide and conquer, as Myers and Miller extended it
+//! to affine gaps: the score of aligning the top half of the first sequence
+//! to each prefix of the second, and the bottom half to each suffix, shows
+//! where the best alignment crosses the middle row, and each half is then
+//! aligned on its own. A gap crossing the middle is opened only once, by
+//! telling each half that a deletion at that end costs nothing to open.
+//!
+//! The recursion is written with costs rather than scores, as Myers and
+//! Miller write it, where a gap of length `k` costs `g + h * k`.
+use std::ops::Range;
+
+use florist_core::{Alphabet, Sequence};
+
+use crate::{
+    pairwise::{Mode, NEG},
+    Aligner, Alignment, Scoring, GAP,
+};
+
+impl<S: Scoring> Aligner<S> {
+    /// The best alignment, found in linear space at about twice the time of
+    /// [Aligner::align]. It scores the same, but of equally good alignments
+    /// may choose a different one.
+    pub fn align_linear<A: Alphabet>(
+        &self,
+        first: &Sequence<A>,
+        second: &Sequence<A>,
+    ) -> Alignment {
+        let a: Vec<char> = first.chars().collect();
+        let b: Vec<char> = second.chars().collect();
+
+        // the best alignment in any mode is a global alignment of the parts
+        // it covers
+        let (first_range, second_range) = self.endpoints(&a, &b);
+        let costs = Costs {
+            scoring: &self.scoring,
+            open: i64::from(self.gaps.open - self.gaps.extend),
+            extend: i64::from(self.gaps.extend),
+        };
+
+        let mut columns = Vec::new();
+        costs.align(
+            &a[first_range.clone()],
+            &b[second_range.clone()],
+            costs.open,
+            costs.open,
+            &mut columns,
+        );
+
+        let mut alignment = Alignment::from_columns(0, first_range, second_range, columns);
+        alignment.score = self.score_rows(&alignment.first, &alignment.second);
+        alignment
+    }
+
+    /// The parts of each sequence the best alignment covers, found a row at
+    /// a time by keeping for each cell where its best alignment started.
+    fn endpoints(&self, a: &[char], b: &[char]) -> (Range<usize>, Range<usize>) {
+        let (n, m) = (a.len(), b.len());
+        if self.mode == Mode::Global {
+            return (0..n, 0..m);
+        }
+
+        // the best score of each table in the row above, and this one, with
+        // the cell its alignment starts at
+        let unaligned = (NEG, (0, 0));
+        let mut prev_best = vec![unaligned; m + 1];
+        let mut prev_deletion = vec![unaligned; m + 1];
+        let mut cur_best = vec![unaligned; m + 1];
+        let mut cur_deletion = vec![unaligned; m + 1];
+        let mut best = (NEG, (0, 0), (0, 0));
+
+        let gap = |extend: (i32, (usize, usize)), open: (i32, (usize, usize))| {
+            let extend = (extend.0 - self.gaps.extend, extend.1);
+            let open = (open.0 - self.gaps.open, open.1);
+            if extend.0 > open.0 {
+                extend
+            } else {
+                open
+            }
+        };
+
+        for i in 0..=n {
+            let mut insertion = unaligned;
+            for j in 0..=m {
+                let diagonal = match (i, j) {
+                    (0, _) | (_, 0) => unaligned,
+                    _ => {
+                        let (score, start) = prev_best[j - 1];
+                        (score + self.scoring.score(a[i - 1], b[j - 1]), start)
+                    }
+                };
+                let deletion = match i {
+                    0 => unaligned,
+                    _ => gap(prev_deletion[j], prev_best[j]),
+                };
+                insertion = match j {
+                    0 => unaligned,
+                    _ => gap(insertion, cur_best[j - 1]),
+                };
+                let start = if self.mode.starts_at(i, j) {
+                    (0, (i, j))
+                } else {
+                    (NEG, (i, j))
+                };
+
+                let cell = [start, diagonal, deletion, insertion]
+                    .into_iter()
+                    .fold(unaligned, |acc, cur| if cur.0 > acc.0 { cur } else { acc });
+                cur_best[j] = cell;
+                cur_deletion[j] = deletion;
+
+                if self.mode.ends_at(i, j, n, m) && cell.0 > best.0 {
+                    best = (cell.0, cell.1, (i, j));
+                }
+            }
+
+            std::mem::swap(&mut prev_best, &mut cur_best);
+            std::mem::swap(&mut prev_deletion, &mut cur_deletion);
+        }
+
+        let (_, (start_i, start_j), (end_i, end_j)) = best;
+        (start_i..end_i, start_j..end_j)
+    }
+}
+
+/// The costs of aligning symbols and gaps, the negated scores.
+struct Costs<'s, S> {
+    scoring: &'s S,
+
+    /// `g`, the extra cost of the first position of a gap.
+    open: i64,
+
+    /// `h`, the cost of every position of a gap.
+    extend: i64,
+}
+
+impl<S: Scoring> Costs<'_, S> {
+    fn substitution(&self, x: char, y: char) -> i64 {
+        -i64::from(self.scoring.score(x, y))
+    }
+
+    fn gap(&self, len: usize) -> i64 {
+        match len {
+            0 => 0,
+            _ => self.open + self.extend * len as i64,
+        }
+    }
+
+    /// Appends the columns of the cheapest global alignment of `a` and `b`,
+    /// where opening a deletion at the start costs `start_open` and at the
+    /// end costs `end_open` instead of `g`.
+    fn align(
+        &self,
+        a: &[char],
+        b: &[char],
+        start_open: i64,
+        end_open: i64,
+        columns: &mut Vec<(char, char)>,
+    ) {
+        let (m, n) = (a.len(), b.len());
+        if n == 0 {
+            columns.extend(a.iter().map(|x| (*x, GAP)));
+            return;
+        }
+        if m <= 1 {
+            self.align_one(
+                a,
+                b,
+                start_open.min(end_open),
+                start_open <= end_open,
+                columns,
+            );
+            return;
+        }
+
+        let mid = m / 2;
+        let (forward, forward_deletion) = self.last_row(a[..mid].iter(), b.iter(), start_open);
+        let (reverse, reverse_deletion) =
+            self.last_row(a[mid..].iter().rev(), b.iter().rev(), end_open);
+
+        // crossing the middle row at column j, either freely or in the middle
+        // of a deletion, which was opened by both halves
+        let mut split = (i64::MAX, 0, false);
+        for j in 0..=n {
+            let crossing = forward[j] + reverse[n - j];
+            if crossing < split.0 {
+                split = (crossing, j, false);
+            }
+            let deleting = forward_deletion[j] + reverse_deletion[n - j] - self.open;
+            if deleting < split.0 {
+                split = (deleting, j, true);
+            }
+        }
+
+        let (_, j, in_deletion) = split;
+        if in_deletion {
+            self.align(&a[..mid - 1], &b[..j], start_open, 0, columns);
+            columns.push((a[mid - 1], GAP));
+            columns.push((a[mid], GAP));
+            self.align(&a[mid + 1..], &b[j..], 0, end_open, columns);
+        } else {
+            self.align(&a[..mid], &b[..j], start_open, self.open, columns);
+            self.align(&a[mid..], &b[j..], self.open, end_open, columns);
+        }
+    }
+
+    /// Aligns at most one symbol of `a` to `b`: deleting it, which costs
+    /// `open` to open before or after the insertions, or aligning it to one
+    /// of `b`'s.
+    fn align_one(
+        &self,
+        a: &[char],
+        b: &[char],
+        open: i64,
+        delete_first: bool,
+        columns: &mut Vec<(char, char)>,
+    ) {
+        let insert = |columns: &mut Vec<(char, char)>, part: &[char]| {
+            columns.extend(part.iter().map(|y| (GAP, *y)));
+        };
+        let Some(&x) = a.first() else {
+            insert(columns, b);
+            return;
+        };
+
+        let n = b.len();
+        let mut best = (open + self.extend + self.gap(n), None);
+        for (j, y) in b.iter().enumerate() {
+            let cost = self.gap(j) + self.substitution(x, *y) + self.gap(n - j - 1);
+            if cost < best.0 {
+                best = (cost, Some(j));
+            }
+        }
+
+        match best.1 {
+            Some(j) => {
+                insert(columns, &b[..j]);
+                columns.push((x, b[j]));
+                insert(columns, &b[j + 1..]);
+            }
+            None if delete_first => {
+                columns.push((x, GAP));
+                insert(columns, b);
+            }
+            None => {
+                insert(columns, b);
+                columns.push((x, GAP));
+            }
+        }
+    }
+
+    /// The cheapest cost of aligning all of `a` to each prefix of `b`, and
+    /// of those ending in a deletion, where a deletion at the start costs
+    /// `start_open` to open.
+    fn last_row<'c>(
+        &self,
+        a: impl Iterator<Item = &'c char>,
+        b: impl Iterator<Item = &'c char> + Clone,
+        start_open: i64,
+    ) -> (Vec<i64>, Vec<i64>) {
+        let n = b.clone().count();
+        let mut best: Vec<i64> = (0..=n).map(|j| self.gap(j)).collect();
+        let mut deletion: Vec<i64> = best.iter().map(|cost| cost + self.open).collect();
+
+        let mut first_column = start_open;
+        for x in a {
+            first_column += self.extend;
+            let mut diagonal = best[0];
+            let mut left = first_column;
+            let mut insertion = first_column + self.open;
+            best[0] = first_column;
+
+            for (j, y) in b.clone().enumerate().map(|(j, y)| (j + 1, y)) {
+                insertion = insertion.min(left + self.open) + self.extend;
+                deletion[j] = deletion[j].min(best[j] + self.open) + self.extend;
+                left = deletion[j]
+                    .min(insertion)
+                    .min(diagonal + self.substitution(*x, *y));
+                diagonal = best[j];
+                best[j] = left;
+            }
+        }
+
+        deletion[0] = best[0];
+        (best, deletion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_core::{Dna, Protein, ScoringMatrix};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::{GapPenalty, MatchMismatch};
+
+    use super::*;
+
+    const MODES: [Mode; 5] = [
+        Mode::Global,
+        Mode::Local,
+        Mode::SemiGlobal,
+        Mode::Fitting,
+        Mode::Overlap,
+    ];
+
+    /// Checks the linear space alignment scores the same as the quadratic
+    /// one, and is a real alignment of the parts of the sequences it covers.
+    fn cross_check<A: Alphabet, S: Scoring>(
+        aligner: &Aligner<S>,
+        first: &Sequence<A>,
+        second: &Sequence<A>,
+    ) {
+        let expected = aligner.align(first, second);
+        let alignment = aligner.align_linear(first, second);
+
+        assert_eq!(
+            alignment.score, expected.score,
+            "{:?} {} {}",
+            aligner.mode, first, second
+        );
+        assert_eq!(alignment.first.len(), alignment.second.len());
+        assert_eq!(
+            alignment.first.replace(GAP, ""),
+            first[alignment.first_range.clone()]
+        );
+        assert_eq!(
+            alignment.second.replace(GAP, ""),
+            second[alignment.second_range.clone()]
+        );
+        assert!(aligner
+            .mode
+            .starts_at(alignment.first_range.start, alignment.second_range.start));
+        assert!(aligner.mode.ends_at(
+            alignment.first_range.end,
+            alignment.second_range.end,
+            first.len(),
+            second.len()
+        ));
+    }
+
+    #[test]
+    fn scores_as_the_quadratic_alignment() {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let len = rng.gen_range(0..60);
+            let first: Sequence<Dna> = florist_gen::sequence(&mut rng, len);
+            let second = florist_gen::evolve(&mut rng, &first, 0.3);
+
+            for mode in MODES {
+                for gaps in [GapPenalty::linear(1), GapPenalty::affine(3, 1)] {
+                    let aligner = Aligner::new(mode, MatchMismatch::new(1, -1), gaps);
+                    cross_check(&aligner, &first, &second);
+                    cross_check(&aligner, &second, &first);
+                }
+            }
+
+            let len = rng.gen_range(0..40);
+            let first: Sequence<Protein> = florist_gen::sequence(&mut rng, len);
+            let len = rng.gen_range(0..40);
+            let second: Sequence<Protein> = florist_gen::sequence(&mut rng, len);
+            for mode in MODES {
+                let aligner =
+                    Aligner::new(mode, ScoringMatrix::blosum62(), GapPenalty::affine(11, 1));
+                cross_check(&aligner, &first, &second);
+            }
+        }
+    }
+
+    #[test]
+    fn aligns_long_sequences() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let first: Sequence<Dna> = florist_gen::sequence(&mut rng, 2000);
+        let second = florist_gen::evolve(&mut rng, &first, 0.1);
+
+        let aligner = Aligner::new(
+            Mode::Global,
+            MatchMismatch::new(2, -3),
+            GapPenalty::affine(5, 2),
+        );
+        let alignment = aligner.align_linear(&first, &second);
+        assert_eq!(alignment.score, aligner.align(&first, &second).score);
+        assert_eq!(
+            aligner.score_rows(&alignment.first, &alignment.second),
+            alignment.score
+        );
+    }
+}
@@ -0,0 +1,460 @@
+//! Multiple alignment of sequences.
+//!
+//! [MultipleAligner::progressive] aligns any number of sequences the usual
+//! way: every pair is aligned to estimate how far apart they are, a guide
+//! tree joins the closest first (UPGMA), and the sequences are merged up the
+//! tree by aligning the profiles of each side. Once rows are aligned their
+//! gaps are kept, so an early mistake is never revisited.
+//!
+//! [MultipleAligner::exact] finds the best alignment of them all at once, in
+//! time exponential in the number of sequences, so it is only practical for
+//! a handful of short ones.
+//!
+//! Both score an alignment by the sum of pairs: every pair of rows is scored
+//! with linear gaps, leaving out the columns where both have a gap.
+use florist_core::{Alphabet, Consensus, Error, GapPolicy, Gapped, Sequence};
+use serde::Serialize;
+
+use crate::{Aligner, GapPenalty, Mode, Scoring, GAP};
+
+/// Sequences aligned together, as rows of the same length in the order the
+/// sequences were given.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(bound = "")]
+pub struct MultipleAlignment<A> {
+    /// The sum of pairs score.
+    pub score: i32,
+
+    pub rows: Vec<Sequence<Gapped<A>>>,
+}
+
+impl<A: Alphabet> MultipleAlignment<A>
+where
+    Gapped<A>: Alphabet,
+{
+    /// The number of columns.
+    pub fn len(&self) -> usize {
+        self.rows.first().map(|row| row.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The consensus of the rows and their profile.
+    pub fn consensus(&self, policy: GapPolicy) -> Result<Consensus<Gapped<A>>, Error> {
+        Consensus::from_alignment(self.rows.iter(), policy)
+    }
+}
+
+/// The sequences in a cluster of the guide tree, and their rows aligned so
+/// far.
+type Cluster = (Vec<usize>, Vec<Vec<char>>);
+
+/// Aligns many sequences with a scoring scheme and a linear gap penalty.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MultipleAligner<S> {
+    pub scoring: S,
+
+    /// The penalty for aligning a symbol to a gap.
+    pub gap: i32,
+}
+
+impl<S: Scoring> MultipleAligner<S> {
+    pub fn new(scoring: S, gap: i32) -> Self {
+        Self { scoring, gap }
+    }
+
+    /// Aligns the sequences progressively along a guide tree.
+    pub fn progressive<A: Alphabet>(&self, seqs: &[Sequence<A>]) -> MultipleAlignment<A>
+    where
+        Gapped<A>: Alphabet,
+    {
+        let pairwise = Aligner::new(Mode::Global, &self.scoring, GapPenalty::linear(self.gap));
+
+        // how unalike each pair is, the fraction of their columns not matching
+        let mut distances = vec![vec![0.0; seqs.len()]; seqs.len()];
+        for i in 0..seqs.len() {
+            for j in 0..i {
+                let distance = 1.0 - pairwise.align(&seqs[i], &seqs[j]).identity();
+                distances[i][j] = distance;
+                distances[j][i] = distance;
+            }
+        }
+
+        let mut clusters: Vec<Option<Cluster>> = seqs
+            .iter()
+            .enumerate()
+            .map(|(i, seq)| Some((vec![i], vec![seq.chars().collect()])))
+            .collect();
+
+        for _ in 1..seqs.len() {
+            let (i, j) = closest(&clusters, &distances);
+            let (first_members, first_rows) = clusters[i].take().unwrap();
+            let (second_members, second_rows) = clusters[j].take().unwrap();
+
+            // UPGMA: the merged cluster is as far from the others as its
+            // sequences are on average
+            let (first_size, second_size) =
+                (first_members.len() as f64, second_members.len() as f64);
+            let merged: Vec<f64> = distances[i]
+                .iter()
+                .zip(&distances[j])
+                .map(|(first, second)| {
+                    (first_size * first + second_size * second) / (first_size + second_size)
+                })
+                .collect();
+            for (row, distance) in distances.iter_mut().zip(&merged) {
+                row[i] = *distance;
+            }
+            distances[i] = merged;
+
+            let rows = self.align_profiles(&first_rows, &second_rows);
+            let members = first_members.into_iter().chain(second_members).collect();
+            clusters[i] = Some((members, rows));
+        }
+
+        let mut rows = vec![Vec::new(); seqs.len()];
+        if let Some((members, aligned)) = clusters.into_iter().flatten().next() {
+            for (member, row) in members.into_iter().zip(aligned) {
+                rows[member] = row;
+            }
+        }
+
+        self.finish(rows)
+    }
+
+    /// The best alignment of all the sequences, by dynamic programming over
+    /// every combination of their prefixes. This takes time proportional to
+    /// the product of their lengths and to two to the power of how many
+    /// there are.
+    ///
+    /// # Panics
+    /// If the table has more cells than fit in memory, or there are 32 or
+    /// more sequences.
+    pub fn exact<A: Alphabet>(&self, seqs: &[Sequence<A>]) -> MultipleAlignment<A>
+    where
+        Gapped<A>: Alphabet,
+    {
+        let symbols: Vec<Vec<char>> = seqs.iter().map(|seq| seq.chars().collect()).collect();
+        let count = symbols.len();
+        assert!(count < 32, "Too many sequences to align exactly");
+        if count == 0 {
+            return self.finish(Vec::new());
+        }
+
+        // each cell is a prefix of every sequence, indexed in mixed radix
+        let strides: Vec<usize> = symbols
+            .iter()
+            .scan(1_usize, |stride, seq| {
+                let cur = *stride;
+                *stride = stride
+                    .checked_mul(seq.len() + 1)
+                    .expect("Too many sequences to align exactly");
+                Some(cur)
+            })
+            .collect();
+        let cells = strides[count - 1] * (symbols[count - 1].len() + 1);
+
+        // for every cell, the best score and which sequences its last column
+        // advances
+        let mut scores = vec![i32::MIN; cells];
+        let mut moves = vec![0_u32; cells];
+        scores[0] = 0;
+
+        let mut prefix = vec![0; count];
+        let mut column = vec![GAP; count];
+        for cell in 1..cells {
+            let mut rest = cell;
+            for (len, stride) in prefix.iter_mut().zip(&strides).rev() {
+                *len = rest / stride;
+                rest %= stride;
+            }
+
+            for advanced in 1..1_u32 << count {
+                let mut previous = cell;
+                let mut possible = true;
+                for (i, symbol) in column.iter_mut().enumerate() {
+                    *symbol = GAP;
+                    if advanced & (1 << i) != 0 {
+                        if prefix[i] == 0 {
+                            possible = false;
+                            break;
+                        }
+                        *symbol = symbols[i][prefix[i] - 1];
+                        previous -= strides[i];
+                    }
+                }
+
+                if possible {
+                    let score = scores[previous] + self.column_score(&column);
+                    if score > scores[cell] {
+                        scores[cell] = score;
+                        moves[cell] = advanced;
+                    }
+                }
+            }
+        }
+
+        let mut rows = vec![Vec::new(); count];
+        let mut cell = cells - 1;
+        while cell > 0 {
+            let advanced = moves[cell];
+            let mut rest = cell;
+            for i in (0..count).rev() {
+                let len = rest / strides[i];
+                rest %= strides[i];
+                if advanced & (1 << i) != 0 {
+                    rows[i].push(symbols[i][len - 1]);
+                    cell -= strides[i];
+                } else {
+                    rows[i].push(GAP);
+                }
+            }
+        }
+
+        for row in rows.iter_mut() {
+            row.reverse();
+        }
+
+        self.finish(rows)
+    }
+
+    /// Aligns two groups of aligned rows, keeping the columns of each, to
+    /// make the best sum of pairs between rows of one and rows of the other.
+    fn align_profiles(&self, first: &[Vec<char>], second: &[Vec<char>]) -> Vec<Vec<char>> {
+        let (n, m) = (first[0].len(), second[0].len());
+        let first_columns: Vec<Vec<char>> = (0..n)
+            .map(|i| first.iter().map(|row| row[i]).collect())
+            .collect();
+        let second_columns: Vec<Vec<char>> = (0..m)
+            .map(|j| second.iter().map(|row| row[j]).collect())
+            .collect();
+        let first_gaps = vec![GAP; first.len()];
+        let second_gaps = vec![GAP; second.len()];
+
+        // the step into each cell: diagonally, down or across
+        const DIAGONAL: u8 = 0;
+        const DOWN: u8 = 1;
+        const ACROSS: u8 = 2;
+
+        let width = m + 1;
+        let mut steps = vec![DIAGONAL; (n + 1) * width];
+        let mut prev = vec![0; width];
+        let mut cur = vec![0; width];
+
+        for i in 0..=n {
+            for j in 0..=m {
+                let candidates = [
+                    (i > 0 && j > 0).then(|| {
+                        prev[j - 1]
+                            + self.cross_score(&first_columns[i - 1], &second_columns[j - 1])
+                    }),
+                    (i > 0)
+                        .then(|| prev[j] + self.cross_score(&first_columns[i - 1], &second_gaps)),
+                    (j > 0).then(|| {
+                        cur[j - 1] + self.cross_score(&first_gaps, &second_columns[j - 1])
+                    }),
+                ];
+
+                let best = candidates
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(step, score)| Some((score?, step as u8)))
+                    .fold(None, |acc: Option<(i32, u8)>, cur| match acc {
+                        Some(best) if best.0 >= cur.0 => Some(best),
+                        _ => Some(cur),
+                    });
+
+                let (score, step) = best.unwrap_or((0, DIAGONAL));
+                cur[j] = score;
+                steps[i * width + j] = step;
+            }
+
+            std::mem::swap(&mut prev, &mut cur);
+        }
+
+        let mut rows = vec![Vec::new(); first.len() + second.len()];
+        let (mut i, mut j) = (n, m);
+        while i > 0 || j > 0 {
+            let (first_column, second_column) = match steps[i * width + j] {
+                DOWN => {
+                    i -= 1;
+                    (&first_columns[i], &second_gaps)
+                }
+                ACROSS => {
+                    j -= 1;
+                    (&first_gaps, &second_columns[j])
+                }
+                _ => {
+                    i -= 1;
+                    j -= 1;
+                    (&first_columns[i], &second_columns[j])
+                }
+            };
+
+            for (row, symbol) in rows
+                .iter_mut()
+                .zip(first_column.iter().chain(second_column))
+            {
+                row.push(*symbol);
+            }
+        }
+
+        for row in rows.iter_mut() {
+            row.reverse();
+        }
+
+        rows
+    }
+
+    /// The score of aligning one symbol to another, either of which may be
+    /// a gap.
+    fn pair_score(&self, x: char, y: char) -> i32 {
+        match (x, y) {
+            (GAP, GAP) => 0,
+            (GAP, _) | (_, GAP) => -self.gap,
+            _ => self.scoring.score(x, y),
+        }
+    }
+
+    /// The sum of pairs of a column.
+    fn column_score(&self, column: &[char]) -> i32 {
+        column
+            .iter()
+            .enumerate()
+            .flat_map(|(i, x)| column[i + 1..].iter().map(|y| self.pair_score(*x, *y)))
+            .sum()
+    }
+
+    /// The sum of pairs between the symbols of one column and those of
+    /// another.
+    fn cross_score(&self, first: &[char], second: &[char]) -> i32 {
+        first
+            .iter()
+            .flat_map(|x| second.iter().map(|y| self.pair_score(*x, *y)))
+            .sum()
+    }
+
+    /// The sum of pairs score of existing rows, which are the same length.
+    pub fn score_rows<A: Alphabet>(&self, rows: &[Sequence<Gapped<A>>]) -> i32 {
+        let rows: Vec<&[u8]> = rows.iter().map(|row| row.as_bytes()).collect();
+        let len = rows.first().map(|row| row.len()).unwrap_or_default();
+
+        (0..len)
+            .map(|i| {
+                // we're okay casting because we _know_ our sequences are ascii
+                let column: Vec<char> = rows.iter().map(|row| row[i] as char).collect();
+                self.column_score(&column)
+            })
+            .sum()
+    }
+
+    fn finish<A: Alphabet>(&self, rows: Vec<Vec<char>>) -> MultipleAlignment<A>
+    where
+        Gapped<A>: Alphabet,
+    {
+        let rows: Vec<Sequence<Gapped<A>>> = rows
+            .into_iter()
+            .map(|row| Sequence::new_unchecked(row.into_iter().collect()))
+            .collect();
+
+        MultipleAlignment {
+            score: self.score_rows(&rows),
+            rows,
+        }
+    }
+}
+
+/// The two clusters still to be merged that are closest together.
+fn closest<T>(clusters: &[Option<T>], distances: &[Vec<f64>]) -> (usize, usize) {
+    let active: Vec<usize> = (0..clusters.len())
+        .filter(|i| clusters[*i].is_some())
+        .collect();
+
+    let mut best = (f64::INFINITY, active[0], active[1]);
+    for (k, i) in active.iter().enumerate() {
+        for j in &active[k + 1..] {
+            if distances[*i][*j] < best.0 {
+                best = (distances[*i][*j], *i, *j);
+            }
+        }
+    }
+
+    (best.1, best.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_core::{DNASequence, Dna};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::MatchMismatch;
+
+    use super::*;
+
+    fn check_rows<A: Alphabet>(alignment: &MultipleAlignment<A>, seqs: &[Sequence<A>])
+    where
+        Gapped<A>: Alphabet,
+    {
+        assert_eq!(alignment.rows.len(), seqs.len());
+        for (row, seq) in alignment.rows.iter().zip(seqs) {
+            assert_eq!(row.len(), alignment.len());
+            assert_eq!(row.ungapped(), *seq);
+        }
+        // no column is all gaps
+        assert!((0..alignment.len()).all(|i| alignment
+            .rows
+            .iter()
+            .any(|row| row.as_bytes()[i] != GAP as u8)));
+    }
+
+    #[test]
+    fn aligns_rosalind_sample() {
+        let seqs: Vec<DNASequence> = ["ATATCCG", "TCCG", "ATGTACTG", "ATGTCTG"]
+            .iter()
+            .map(|raw| DNASequence::from_str(raw).unwrap())
+            .collect();
+        let aligner = MultipleAligner::new(MatchMismatch::new(0, -1), 1);
+
+        let exact = aligner.exact(&seqs);
+        assert_eq!(exact.score, -18);
+        check_rows(&exact, &seqs);
+
+        let progressive = aligner.progressive(&seqs);
+        check_rows(&progressive, &seqs);
+        assert!(progressive.score <= exact.score);
+
+        let consensus = exact.consensus(GapPolicy::Ignore).unwrap();
+        assert_eq!(consensus.ungapped_sequence().len(), exact.len());
+    }
+
+    #[test]
+    fn progressive_alignment_agrees_for_pairs() {
+        let aligner = MultipleAligner::new(MatchMismatch::new(1, -1), 2);
+        let pairwise = Aligner::new(
+            Mode::Global,
+            MatchMismatch::new(1, -1),
+            GapPenalty::linear(2),
+        );
+
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let len = rng.gen_range(1..30);
+            let first: Sequence<Dna> = florist_gen::sequence(&mut rng, len);
+            let seqs = [first.clone(), florist_gen::evolve(&mut rng, &first, 0.3)];
+
+            let expected = pairwise.align(&seqs[0], &seqs[1]).score;
+            assert_eq!(aligner.progressive(&seqs).score, expected);
+            assert_eq!(aligner.exact(&seqs).score, expected);
+
+            // more sequences are aligned, though not always at their best
+            let more: Vec<Sequence<Dna>> = (0..5)
+                .map(|_| florist_gen::evolve(&mut rng, &first, 0.2))
+                .collect();
+            check_rows(&aligner.progressive(&more), &more);
+        }
+    }
+}
@@ -0,0 +1,528 @@
+//! Dynamic programming alignment of two sequences, in every mode Rosalind
+//! asks for.
+//!
+//! Gaps are scored with Gotoh's three tables, one for alignments ending in
+//! each kind of column, so linear and affine penalties take the same path.
+//! Only the traceback is kept for the whole table, a byte per cell, while
+//! the scores are kept a row at a time. A banded alignment keeps the
+//! traceback for only the cells near the diagonal.
+use std::ops::RangeInclusive;
+
+use florist_core::{Alphabet, Sequence};
+
+use crate::{Alignment, Cigar, GapPenalty, MatchMismatch, Operation, Scoring, GAP};
+
+/// Which parts of the sequences must be aligned.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum Mode {
+    /// Both sequences end to end (Needleman-Wunsch).
+    #[default]
+    Global,
+
+    /// The best scoring parts of each (Smith-Waterman).
+    Local,
+
+    /// Both sequences, with gaps at either end of either one free.
+    SemiGlobal,
+
+    /// The whole of the second sequence against part of the first.
+    Fitting,
+
+    /// A suffix of the first sequence against a prefix of the second.
+    Overlap,
+}
+
+impl Mode {
+    /// Whether an alignment may start at the cell.
+    pub(crate) fn starts_at(&self, i: usize, j: usize) -> bool {
+        match self {
+            Self::Global => i == 0 && j == 0,
+            Self::Local => true,
+            Self::SemiGlobal => i == 0 || j == 0,
+            Self::Fitting | Self::Overlap => j == 0,
+        }
+    }
+
+    /// Whether an alignment may end at the cell, for sequences of the given
+    /// lengths.
+    pub(crate) fn ends_at(&self, i: usize, j: usize, n: usize, m: usize) -> bool {
+        match self {
+            Self::Global => i == n && j == m,
+            Self::Local => true,
+            Self::SemiGlobal => i == n || j == m,
+            Self::Fitting => j == m,
+            Self::Overlap => i == n,
+        }
+    }
+}
+
+/// The table an alignment ending at a cell comes from, in the order ties
+/// are broken: a fresh start beats extending an alignment that scores
+/// nothing.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(u8)]
+enum State {
+    Start = 0,
+    Diagonal = 1,
+    Deletion = 2,
+    Insertion = 3,
+}
+
+/// The traceback for a cell: the best state in the low bits, then whether
+/// the deletion and insertion tables extend a gap rather than open one.
+#[derive(Debug, Clone, Copy, Default)]
+struct Trace(u8);
+
+impl Trace {
+    const EXTENDS_DELETION: u8 = 1 << 2;
+    const EXTENDS_INSERTION: u8 = 1 << 3;
+
+    fn best(&self) -> State {
+        match self.0 & 0b11 {
+            0 => State::Start,
+            1 => State::Diagonal,
+            2 => State::Deletion,
+            _ => State::Insertion,
+        }
+    }
+}
+
+/// The traceback for the cells of each row that may be aligned, which is
+/// all of them unless the alignment is banded.
+#[derive(Debug, Default)]
+struct Traces {
+    /// Where each row starts in `cells`, and the first column it has.
+    rows: Vec<(usize, usize)>,
+    cells: Vec<Trace>,
+}
+
+impl Traces {
+    fn start_row(&mut self, first_column: usize) {
+        self.rows.push((self.cells.len(), first_column));
+    }
+
+    fn push(&mut self, trace: Trace) {
+        self.cells.push(trace);
+    }
+
+    fn get(&self, i: usize, j: usize) -> Trace {
+        let (offset, first_column) = self.rows[i];
+        self.cells[offset + j - first_column]
+    }
+}
+
+/// The columns of row `i` an alignment of sequences of lengths `n` and `m`
+/// may pass through: all of them, or those within `band` diagonals of the
+/// ones through both corners.
+fn band_columns(i: usize, n: usize, m: usize, band: Option<usize>) -> RangeInclusive<usize> {
+    let Some(band) = band else {
+        return 0..=m;
+    };
+
+    let (i, n, m, band) = (i as isize, n as isize, m as isize, band as isize);
+    let lowest = i + (m - n).min(0) - band;
+    let highest = i + (m - n).max(0) + band;
+    (lowest.max(0) as usize)..=(highest.min(m) as usize)
+}
+
+/// Low enough that no alignment through it is ever chosen, but high enough
+/// not to overflow when penalised.
+pub(crate) const NEG: i32 = i32::MIN / 4;
+
+/// Aligns pairs of sequences with a scoring scheme.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Aligner<S> {
+    pub mode: Mode,
+    pub scoring: S,
+    pub gaps: GapPenalty,
+}
+
+impl<S: Scoring> Aligner<S> {
+    pub fn new(mode: Mode, scoring: S, gaps: GapPenalty) -> Self {
+        Self {
+            mode,
+            scoring,
+            gaps,
+        }
+    }
+
+    /// The best alignment of the two sequences. Of equally good alignments,
+    /// the one chosen is traced back from the end preferring a fresh start,
+    /// then matches, deletions and insertions in that order.
+    pub fn align<A: Alphabet>(&self, first: &Sequence<A>, second: &Sequence<A>) -> Alignment {
+        self.align_within(first, second, None)
+    }
+
+    /// The best alignment that keeps within `band` diagonals of those
+    /// through the start and end of both sequences, in time and memory
+    /// proportional to the length times the band rather than to the product
+    /// of the lengths. Whenever the best alignment has at most `band` gapped
+    /// columns it lies within the band, so this finds it.
+    pub fn align_banded<A: Alphabet>(
+        &self,
+        first: &Sequence<A>,
+        second: &Sequence<A>,
+        band: usize,
+    ) -> Alignment {
+        self.align_within(first, second, Some(band))
+    }
+
+    fn align_within<A: Alphabet>(
+        &self,
+        first: &Sequence<A>,
+        second: &Sequence<A>,
+        band: Option<usize>,
+    ) -> Alignment {
+        let a: Vec<char> = first.chars().collect();
+        let b: Vec<char> = second.chars().collect();
+        let (n, m) = (a.len(), b.len());
+
+        let mut traces = Traces::default();
+        let mut best = (NEG, 0, 0);
+
+        // the best score of each table in the row above, and this one
+        let mut prev_best = vec![NEG; m + 1];
+        let mut prev_deletion = vec![NEG; m + 1];
+        let mut cur_best = vec![NEG; m + 1];
+        let mut cur_deletion = vec![NEG; m + 1];
+
+        for i in 0..=n {
+            let columns = band_columns(i, n, m, band);
+            let (lo, hi) = (*columns.start(), *columns.end());
+            // the cells either side of the band are never aligned
+            if lo > 0 {
+                cur_best[lo - 1] = NEG;
+            }
+            traces.start_row(lo);
+
+            let mut insertion = NEG;
+            for j in columns {
+                let mut trace = 0;
+
+                let diagonal = match (i, j) {
+                    (0, _) | (_, 0) => NEG,
+                    _ => prev_best[j - 1] + self.scoring.score(a[i - 1], b[j - 1]),
+                };
+
+                let deletion = if i == 0 {
+                    NEG
+                } else if prev_deletion[j] - self.gaps.extend > prev_best[j] - self.gaps.open {
+                    trace |= Trace::EXTENDS_DELETION;
+                    prev_deletion[j] - self.gaps.extend
+                } else {
+                    prev_best[j] - self.gaps.open
+                };
+
+                insertion = if j == 0 {
+                    NEG
+                } else if insertion - self.gaps.extend > cur_best[j - 1] - self.gaps.open {
+                    trace |= Trace::EXTENDS_INSERTION;
+                    insertion - self.gaps.extend
+                } else {
+                    cur_best[j - 1] - self.gaps.open
+                };
+
+                let start = if self.mode.starts_at(i, j) { 0 } else { NEG };
+                let (score, state) =
+                    [
+                        (start, State::Start),
+                        (diagonal, State::Diagonal),
+                        (deletion, State::Deletion),
+                        (insertion, State::Insertion),
+                    ]
+                    .into_iter()
+                    .fold((NEG, State::Start), |acc, cur| {
+                        if cur.0 > acc.0 {
+                            cur
+                        } else {
+                            acc
+                        }
+                    });
+
+                traces.push(Trace(trace | state as u8));
+                cur_best[j] = score;
+                cur_deletion[j] = deletion;
+
+                if self.mode.ends_at(i, j, n, m) && score > best.0 {
+                    best = (score, i, j);
+                }
+            }
+
+            if hi < m {
+                cur_best[hi + 1] = NEG;
+                cur_deletion[hi + 1] = NEG;
+            }
+
+            std::mem::swap(&mut prev_best, &mut cur_best);
+            std::mem::swap(&mut prev_deletion, &mut cur_deletion);
+        }
+
+        self.traceback(&a, &b, &traces, best)
+    }
+
+    /// Scores two rows of an existing alignment, which are the same length
+    /// with [GAP] for gaps. Whatever is left unaligned by the mode is not
+    /// scored, so only the aligned part of each sequence should be given.
+    pub fn score_rows(&self, first: &str, second: &str) -> i32 {
+        let mut score = 0;
+        // the sequence with the open gap, and how long it is
+        let mut gap: Option<(bool, usize)> = None;
+
+        for (x, y) in first.chars().zip(second.chars()) {
+            let cur = match (x, y) {
+                (GAP, _) => Some(false),
+                (_, GAP) => Some(true),
+                _ => None,
+            };
+
+            gap = match (gap, cur) {
+                (Some((open, len)), Some(next)) if open == next => Some((open, len + 1)),
+                (prev, next) => {
+                    if let Some((_, len)) = prev {
+                        score -= self.gaps.cost(len);
+                    }
+                    if next.is_none() {
+                        score += self.scoring.score(x, y);
+                    }
+                    next.map(|open| (open, 1))
+                }
+            };
+        }
+
+        if let Some((_, len)) = gap {
+            score -= self.gaps.cost(len);
+        }
+
+        score
+    }
+
+    fn traceback(
+        &self,
+        a: &[char],
+        b: &[char],
+        traces: &Traces,
+        (score, end_i, end_j): (i32, usize, usize),
+    ) -> Alignment {
+        let (mut i, mut j) = (end_i, end_j);
+        let mut state = traces.get(i, j).best();
+        let mut columns = Vec::new();
+
+        loop {
+            let trace = traces.get(i, j);
+            match state {
+                State::Start => break,
+                State::Diagonal => {
+                    columns.push((a[i - 1], b[j - 1]));
+                    i -= 1;
+                    j -= 1;
+                    state = traces.get(i, j).best();
+                }
+                State::Deletion => {
+                    columns.push((a[i - 1], GAP));
+                    i -= 1;
+                    if trace.0 & Trace::EXTENDS_DELETION == 0 {
+                        state = traces.get(i, j).best();
+                    }
+                }
+                State::Insertion => {
+                    columns.push((GAP, b[j - 1]));
+                    j -= 1;
+                    if trace.0 & Trace::EXTENDS_INSERTION == 0 {
+                        state = traces.get(i, j).best();
+                    }
+                }
+            }
+        }
+
+        columns.reverse();
+        Alignment::from_columns(score, i..end_i, j..end_j, columns)
+    }
+}
+
+impl Aligner<MatchMismatch> {
+    /// Global alignment where every edit costs one, so the score is minus
+    /// the Levenshtein distance.
+    pub fn levenshtein() -> Self {
+        Self::new(
+            Mode::Global,
+            MatchMismatch::new(0, -1),
+            GapPenalty::linear(1),
+        )
+    }
+}
+
+/// The fewest substitutions, insertions and deletions turning one sequence
+/// into the other.
+pub fn edit_distance<A: Alphabet>(first: &Sequence<A>, second: &Sequence<A>) -> u64 {
+    -Aligner::levenshtein().align(first, second).score as u64
+}
+
+impl Alignment {
+    /// Builds the alignment from its columns, each a pair of aligned symbols
+    /// where either may be a gap.
+    pub(crate) fn from_columns(
+        score: i32,
+        first_range: std::ops::Range<usize>,
+        second_range: std::ops::Range<usize>,
+        columns: Vec<(char, char)>,
+    ) -> Self {
+        let cigar = columns
+            .iter()
+            .map(|(x, y)| match (*x, *y) {
+                (GAP, _) => Operation::Insertion,
+                (_, GAP) => Operation::Deletion,
+                (x, y) if x == y => Operation::Match,
+                _ => Operation::Mismatch,
+            })
+            .collect::<Cigar>();
+
+        Self {
+            score,
+            first_range,
+            second_range,
+            first: columns.iter().map(|(x, _)| x).collect(),
+            second: columns.iter().map(|(_, y)| y).collect(),
+            cigar,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_core::{DNASequence, ProteinSequence, ScoringMatrix};
+
+    use super::*;
+
+    fn protein(raw: &str) -> ProteinSequence {
+        ProteinSequence::from_str(raw).unwrap()
+    }
+
+    fn dna(raw: &str) -> DNASequence {
+        DNASequence::from_str(raw).unwrap()
+    }
+
+    #[test]
+    fn aligns_globally() {
+        let (first, second) = (protein("PLEASANTLY"), protein("MEANLY"));
+        assert_eq!(edit_distance(&first, &second), 5);
+
+        let alignment = Aligner::levenshtein().align(&first, &second);
+        assert_eq!(alignment.edits(), 5);
+        assert_eq!(alignment.first.replace(GAP, ""), *first);
+        assert_eq!(alignment.second.replace(GAP, ""), *second);
+
+        let blosum = Aligner::new(
+            Mode::Global,
+            ScoringMatrix::blosum62(),
+            GapPenalty::linear(5),
+        );
+        let alignment = blosum.align(&first, &second);
+        assert_eq!(alignment.score, 8);
+        assert_eq!(blosum.score_rows(&alignment.first, &alignment.second), 8);
+    }
+
+    #[test]
+    fn aligns_with_affine_gaps() {
+        let aligner = Aligner::new(
+            Mode::Global,
+            ScoringMatrix::blosum62(),
+            GapPenalty::affine(11, 1),
+        );
+        let alignment = aligner.align(&protein("PRTEINS"), &protein("PRTWPSEIN"));
+        assert_eq!(alignment.score, 8);
+        assert_eq!(aligner.score_rows(&alignment.first, &alignment.second), 8);
+        assert_eq!(alignment.cigar.to_string(), "3=3I3=1D");
+    }
+
+    #[test]
+    fn aligns_locally() {
+        let aligner = Aligner::new(Mode::Local, ScoringMatrix::pam250(), GapPenalty::linear(5));
+        let alignment = aligner.align(
+            &protein("MEANLYPRTEINSTRING"),
+            &protein("PLEASANTLYEINSTEIN"),
+        );
+        assert_eq!(alignment.score, 23);
+        assert_eq!(aligner.score_rows(&alignment.first, &alignment.second), 23);
+        // Rosalind's answer, LYPRTEINSTRIN against LEASANTLYEINSTEIN, is
+        // just as good
+        assert_eq!(alignment.first.replace(GAP, ""), "MEANLYPRTEINSTRIN");
+        assert_eq!(alignment.second.replace(GAP, ""), "LEASANTLYEINSTEIN");
+        assert_eq!(alignment.first_range, 0..17);
+        assert_eq!(alignment.second_range, 1..18);
+
+        let aligner = Aligner::new(
+            Mode::Local,
+            ScoringMatrix::blosum62(),
+            GapPenalty::affine(11, 1),
+        );
+        let alignment = aligner.align(&protein("PLEASANTLY"), &protein("MEANLY"));
+        assert_eq!(alignment.score, 12);
+        assert_eq!(alignment.first, "LEAS");
+        assert_eq!(alignment.second, "MEAN");
+    }
+
+    #[test]
+    fn fits_and_overlaps() {
+        let fitting = Aligner::new(
+            Mode::Fitting,
+            MatchMismatch::new(1, -1),
+            GapPenalty::linear(1),
+        );
+        let alignment = fitting.align(&dna("GTAGGCTTAAGGTTA"), &dna("TAGATA"));
+        assert_eq!(alignment.score, 2);
+        assert_eq!(alignment.second.replace(GAP, ""), "TAGATA");
+        assert_eq!(fitting.score_rows(&alignment.first, &alignment.second), 2);
+
+        let overlap = Aligner::new(
+            Mode::Overlap,
+            MatchMismatch::new(1, -2),
+            GapPenalty::linear(2),
+        );
+        let (first, second) = (
+            dna("CTAAGGGATTCCGGTAATTAGACAG"),
+            dna("ATAGACCATATGTCAGTGACTGTGTAA"),
+        );
+        let alignment = overlap.align(&first, &second);
+        assert_eq!(alignment.score, 1);
+        assert_eq!(alignment.first_range.end, first.len());
+        assert_eq!(alignment.second_range.start, 0);
+        assert_eq!(overlap.score_rows(&alignment.first, &alignment.second), 1);
+
+        // end gaps are free in either sequence
+        let semi = Aligner::new(
+            Mode::SemiGlobal,
+            MatchMismatch::new(1, -1),
+            GapPenalty::linear(1),
+        );
+        let alignment = semi.align(&dna("AAACGT"), &dna("CGTTTT"));
+        assert_eq!(alignment.score, 3);
+        assert_eq!(
+            (alignment.first_range, alignment.second_range),
+            (3..6, 0..3)
+        );
+    }
+
+    #[test]
+    fn aligns_within_a_band() {
+        let aligner = Aligner::new(
+            Mode::Global,
+            MatchMismatch::new(1, -1),
+            GapPenalty::linear(1),
+        );
+        let (first, second) = (dna("ACGTTGCAACGT"), dna("ACGTGCAACGTAA"));
+        let expected = aligner.align(&first, &second);
+
+        // two gaps are needed, so a band of two finds the best alignment
+        for band in [2, 3, 20] {
+            assert_eq!(aligner.align_banded(&first, &second, band), expected);
+        }
+
+        // without room for the gap, the best is a worse alignment
+        let narrow = aligner.align_banded(&first, &second, 0);
+        assert!(narrow.score < expected.score);
+        assert_eq!(narrow.first.replace(GAP, ""), *first);
+        assert_eq!(narrow.second.replace(GAP, ""), *second);
+    }
+}
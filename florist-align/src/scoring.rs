@@ -0,0 +1,106 @@
+//! How aligned symbols and gaps are scored.
+use florist_core::{NucleicAlphabet, NucleotideScoring, ScoringMatrix};
+
+/// Scores a pair of aligned symbols, higher being more alike.
+pub trait Scoring {
+    fn score(&self, a: char, b: char) -> i32;
+}
+
+impl<S: Scoring + ?Sized> Scoring for &S {
+    fn score(&self, a: char, b: char) -> i32 {
+        (**self).score(a, b)
+    }
+}
+
+/// Scores every match the same, and every mismatch the same.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct MatchMismatch {
+    pub matches: i32,
+
+    /// The score of a mismatch, usually negative.
+    pub mismatches: i32,
+}
+
+impl MatchMismatch {
+    pub fn new(matches: i32, mismatches: i32) -> Self {
+        Self {
+            matches,
+            mismatches,
+        }
+    }
+}
+
+impl Scoring for MatchMismatch {
+    fn score(&self, a: char, b: char) -> i32 {
+        if a == b {
+            self.matches
+        } else {
+            self.mismatches
+        }
+    }
+}
+
+/// The penalty for a gap, subtracted from the score. A gap of length `n`
+/// costs `open + (n - 1) * extend`, so a linear penalty is one where both
+/// are the same.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct GapPenalty {
+    pub open: i32,
+    pub extend: i32,
+}
+
+impl GapPenalty {
+    /// Every gapped position costs the same.
+    pub fn linear(penalty: i32) -> Self {
+        Self {
+            open: penalty,
+            extend: penalty,
+        }
+    }
+
+    /// Opening a gap costs `open`, and each position after the first costs
+    /// `extend`.
+    pub fn affine(open: i32, extend: i32) -> Self {
+        Self { open, extend }
+    }
+
+    /// What a gap of the given length costs.
+    pub fn cost(&self, len: usize) -> i32 {
+        match len {
+            0 => 0,
+            _ => self.open + (len as i32 - 1) * self.extend,
+        }
+    }
+}
+
+impl Scoring for ScoringMatrix {
+    fn score(&self, a: char, b: char) -> i32 {
+        self.score_symbols(a, b)
+    }
+}
+
+impl<A: NucleicAlphabet> Scoring for NucleotideScoring<A> {
+    fn score(&self, a: char, b: char) -> i32 {
+        NucleotideScoring::score(self, a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_core_schemes() {
+        assert_eq!(Scoring::score(ScoringMatrix::blosum62(), 'W', 'W'), 11);
+
+        let scoring = NucleotideScoring::<florist_core::Dna>::new(1, -1, -2);
+        assert_eq!(Scoring::score(&scoring, 'A', 'G'), -1);
+    }
+
+    #[test]
+    fn costs_gaps() {
+        assert_eq!(GapPenalty::linear(5).cost(3), 15);
+        assert_eq!(GapPenalty::affine(11, 1).cost(3), 13);
+        assert_eq!(GapPenalty::affine(11, 1).cost(0), 0);
+    }
+}
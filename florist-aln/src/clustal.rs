@@ -0,0 +1,145 @@
+//! Clustal `.aln` files, as written by Clustal and most aligners.
+//!
+//! After a header line starting `CLUSTAL`, the alignment is written in
+//! blocks of columns. Each block has a line per sequence, its name then its
+//! part of the row, optionally followed by how many symbols the row has had
+//! so far. A line under the block marks each fully conserved column with `*`.
+use std::fmt::Write;
+
+use florist_core::GAP;
+
+use crate::{AlignedSequences, Error};
+
+/// The number of columns written per block.
+const BLOCK_WIDTH: usize = 60;
+
+pub(crate) fn read(s: &str) -> Result<AlignedSequences, Error> {
+    let mut lines = s.lines().skip_while(|line| line.trim().is_empty());
+    if !lines.next().is_some_and(|line| line.starts_with("CLUSTAL")) {
+        return Err(Error::MissingClustalHeader);
+    }
+
+    let mut rows: Vec<(String, String)> = Vec::new();
+    // the names seen in the current block
+    let mut block: Vec<&str> = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() {
+            block.clear();
+            continue;
+        }
+        // conservation lines start with spaces, under the rows
+        if line.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let (Some(name), Some(part)) = (fields.next(), fields.next()) else {
+            return Err(Error::InvalidClustalLine(line.to_string()));
+        };
+        if fields
+            .next()
+            .is_some_and(|count| count.parse::<usize>().is_err())
+        {
+            return Err(Error::InvalidClustalLine(line.to_string()));
+        }
+
+        if block.contains(&name) {
+            return Err(Error::DuplicateClustalName(name.to_string()));
+        }
+        block.push(name);
+
+        match rows.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, row)) => row.push_str(part),
+            None => rows.push((name.to_string(), part.to_string())),
+        }
+    }
+
+    AlignedSequences::new(rows)
+}
+
+pub(crate) fn write(aligned: &AlignedSequences) -> String {
+    let name_width = aligned.name_width();
+    let mut output = String::from("CLUSTAL W multiple sequence alignment\n");
+
+    // rows are checked to be ASCII, so splitting on byte boundaries is safe
+    for start in (0..aligned.columns()).step_by(BLOCK_WIDTH) {
+        let end = (start + BLOCK_WIDTH).min(aligned.columns());
+        output.push('\n');
+
+        for (name, row) in aligned.named_rows() {
+            // writing to a String cannot fail
+            writeln!(output, "{:<name_width$}  {}", name, &row[start..end]).unwrap();
+        }
+
+        let conservation: String = (start..end)
+            .map(|i| {
+                let mut column = aligned.iter().map(|(_, row)| row.as_bytes()[i] as char);
+                let first = column.next().unwrap_or(GAP);
+                if first != GAP && column.all(|symbol| symbol == first) {
+                    '*'
+                } else {
+                    ' '
+                }
+            })
+            .collect();
+        let marks = format!("{:name_width$}  {}", "", conservation);
+        writeln!(output, "{}", marks.trim_end()).unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let aligned = AlignedSequences::new(vec![
+            ("first".to_string(), "ACGT-".repeat(13)),
+            ("second".to_string(), "ACGTT".repeat(13)),
+        ])
+        .unwrap();
+
+        let written = aligned.to_clustal();
+        assert!(written.starts_with("CLUSTAL W multiple sequence alignment\n\nfirst   ACGT-"));
+        assert!(written.contains("\n        **** ****"));
+        assert_eq!(AlignedSequences::from_clustal(&written), Ok(aligned));
+    }
+
+    #[test]
+    fn reads_other_aligners() {
+        let raw = "CLUSTAL O(1.2.4) multiple sequence alignment
+
+
+seq1      ATAT-CCG 7
+seq2      -T---CCG 4
+           *   ***
+
+seq1      TT 9
+seq2      T- 5
+";
+        let aligned = AlignedSequences::from_clustal(raw).unwrap();
+        assert_eq!(aligned[0], ("seq1".to_string(), "ATAT-CCGTT".to_string()));
+        assert_eq!(aligned[1], ("seq2".to_string(), "-T---CCGT-".to_string()));
+
+        assert_eq!(
+            AlignedSequences::from_clustal(">seq1\nACGT\n"),
+            Err(Error::MissingClustalHeader)
+        );
+        assert_eq!(
+            AlignedSequences::from_clustal("CLUSTAL W\n\nseq1\n"),
+            Err(Error::InvalidClustalLine("seq1".to_string()))
+        );
+        assert_eq!(
+            AlignedSequences::from_clustal("CLUSTAL W\n\n"),
+            Err(Error::NoSequences)
+        );
+
+        // a name twice in a block is two rows, not one row continued
+        assert_eq!(
+            AlignedSequences::from_clustal("CLUSTAL W\n\nx  AC\nx  AG\n"),
+            Err(Error::DuplicateClustalName("x".to_string()))
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Alignments as text, to look at and to exchange with other tools.
+//!
+//! [AlignedSequences] are the named rows of a multiple alignment. They are
+//! read from and written as aligned FASTA and Clustal `.aln`, and written as
+//! Stockholm, and can be loaded as gapped [Sequence]s to build a
+//! [Consensus](florist_core::Consensus) or compare rows. A pairwise
+//! [Alignment](florist_align::Alignment) is shown as a BLAST-style
+//! [PairwiseView].
+use std::ops::Deref;
+
+use florist_align::MultipleAlignment;
+use florist_core::{Alphabet, Gapped, Sequence};
+use florist_fasta::OrderedMultiFasta;
+
+pub mod clustal;
+pub mod pairwise;
+pub mod stockholm;
+
+pub use pairwise::PairwiseView;
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Fasta(#[from] florist_fasta::Error),
+
+    #[error("No sequences in alignment")]
+    NoSequences,
+
+    #[error("Row {0} has symbols outside ASCII")]
+    NonAsciiRow(String),
+
+    #[error("Row number {0} has no name")]
+    MissingName(usize),
+
+    #[error("Row {name} has {len} columns, but the alignment has {expected}")]
+    WrongRowLength {
+        name: String,
+        len: usize,
+        expected: usize,
+    },
+
+    #[error("Expected {expected} names, but got {actual}")]
+    WrongNumberOfNames { expected: usize, actual: usize },
+
+    #[error("Missing CLUSTAL header")]
+    MissingClustalHeader,
+
+    #[error("Invalid Clustal line: {0}")]
+    InvalidClustalLine(String),
+
+    #[error("Row {0} appears twice in a Clustal block")]
+    DuplicateClustalName(String),
+}
+
+/// The rows of a multiple alignment, each with the name of its sequence, in
+/// the order they were given. There is always at least one row, and every
+/// row is the same length and ASCII, so columns can be sliced by byte.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AlignedSequences(Vec<(String, String)>);
+
+impl AlignedSequences {
+    /// Checks there are rows, that they all have a name, and that they are
+    /// all ASCII and the same length.
+    pub fn new(rows: Vec<(String, String)>) -> Result<Self, Error> {
+        // a nameless row would be written to Clustal like a conservation line
+        if let Some(i) = rows.iter().position(|(name, _)| name.trim().is_empty()) {
+            return Err(Error::MissingName(i + 1));
+        }
+        if let Some((name, _)) = rows.iter().find(|(_, row)| !row.is_ascii()) {
+            return Err(Error::NonAsciiRow(name.clone()));
+        }
+
+        let expected = rows.first().ok_or(Error::NoSequences)?.1.len();
+        if let Some((name, row)) = rows.iter().find(|(_, row)| row.len() != expected) {
+            return Err(Error::WrongRowLength {
+                name: name.clone(),
+                len: row.len(),
+                expected,
+            });
+        }
+
+        Ok(Self(rows))
+    }
+
+    /// Names the rows of a multiple alignment, in order.
+    pub fn from_alignment<A: Alphabet>(
+        names: Vec<String>,
+        alignment: &MultipleAlignment<A>,
+    ) -> Result<Self, Error>
+    where
+        Gapped<A>: Alphabet,
+    {
+        if names.len() != alignment.rows.len() {
+            return Err(Error::WrongNumberOfNames {
+                expected: alignment.rows.len(),
+                actual: names.len(),
+            });
+        }
+
+        let rows = alignment.rows.iter().map(|row| row.to_string());
+        Self::new(names.into_iter().zip(rows).collect())
+    }
+
+    /// Reads aligned FASTA, where each record is a row with its gaps.
+    pub fn from_fasta(s: &str) -> Result<Self, Error> {
+        let fasta: OrderedMultiFasta = s.parse()?;
+        Self::new(fasta.to_vec())
+    }
+
+    /// Reads a Clustal `.aln` file.
+    pub fn from_clustal(s: &str) -> Result<Self, Error> {
+        clustal::read(s)
+    }
+
+    /// The number of columns.
+    pub fn columns(&self) -> usize {
+        self.0[0].1.len()
+    }
+
+    /// The rows as gapped sequences, failing on the first row with a symbol
+    /// not in the alphabet.
+    pub fn sequences<A: Alphabet>(&self) -> Result<Vec<Sequence<Gapped<A>>>, florist_core::Error>
+    where
+        Gapped<A>: Alphabet,
+    {
+        self.0.iter().map(|(_, row)| row.parse()).collect()
+    }
+
+    /// Writes as aligned FASTA.
+    pub fn to_fasta(&self) -> String {
+        let mut fasta = OrderedMultiFasta::default();
+        fasta.extend(self.0.iter().cloned());
+        fasta.to_string()
+    }
+
+    /// Writes as a Clustal `.aln` file.
+    pub fn to_clustal(&self) -> String {
+        clustal::write(self)
+    }
+
+    /// Writes as a Stockholm file.
+    pub fn to_stockholm(&self) -> String {
+        stockholm::write(self)
+    }
+
+    /// The rows with their names cut to the first word, as the formats with
+    /// names and rows on one line need. The rest of a FASTA header is a
+    /// description, which those formats have nowhere to put.
+    fn named_rows(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(name, row)| {
+            let id = name.split_whitespace().next().unwrap_or_default();
+            (id, row.as_str())
+        })
+    }
+
+    /// The width names are padded to, so the rows line up.
+    fn name_width(&self) -> usize {
+        self.named_rows()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Deref for AlignedSequences {
+    type Target = Vec<(String, String)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::{MatchMismatch, MultipleAligner};
+    use florist_core::{Consensus, DNASequence, Dna, GapPolicy, HammingDistance};
+
+    use super::*;
+
+    #[test]
+    fn reads_aligned_fasta() {
+        let raw = ">seq1\nATAT-CCG\n>seq2\n-T---CCG\n>seq3\nATGTACTG\n";
+        let aligned = AlignedSequences::from_fasta(raw).unwrap();
+        assert_eq!(aligned.len(), 3);
+        assert_eq!(aligned.columns(), 8);
+        assert_eq!(aligned.to_fasta(), raw);
+
+        let rows = aligned.sequences::<Dna>().unwrap();
+        let consensus = Consensus::from_alignment(rows.iter(), GapPolicy::Count).unwrap();
+        assert_eq!(consensus.sequence().as_str(), "ATGT-CCG");
+        assert_eq!(rows[0].hamming_distance(&rows[2]).unwrap(), 3);
+
+        assert_eq!(
+            AlignedSequences::from_fasta(">seq1\nAT-G\n>seq2\nATG\n"),
+            Err(Error::WrongRowLength {
+                name: "seq2".to_string(),
+                len: 3,
+                expected: 4
+            })
+        );
+        assert!(matches!(
+            AlignedSequences::from_fasta(""),
+            Err(Error::Fasta(_))
+        ));
+        assert!(AlignedSequences::from_fasta(">seq1\nAT-U\n")
+            .unwrap()
+            .sequences::<Dna>()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_non_ascii_rows() {
+        // the same number of symbols, but more bytes
+        let raw = format!(">seq1\n{}é\n>seq2\n{}\n", "A".repeat(59), "A".repeat(61));
+        assert_eq!(
+            AlignedSequences::from_fasta(&raw),
+            Err(Error::NonAsciiRow("seq1".to_string()))
+        );
+        assert_eq!(
+            AlignedSequences::new(vec![("é".to_string(), "AC-G".to_string())])
+                .map(|aligned| aligned.columns()),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    fn rejects_rows_without_names() {
+        assert_eq!(
+            AlignedSequences::from_fasta(">\nAC\n>b\nAG\n"),
+            Err(Error::MissingName(1))
+        );
+        assert_eq!(
+            AlignedSequences::new(vec![
+                ("a".to_string(), "AC".to_string()),
+                ("  ".to_string(), "AG".to_string()),
+            ]),
+            Err(Error::MissingName(2))
+        );
+    }
+
+    #[test]
+    fn writes_names_without_descriptions() {
+        let raw = ">seq1 human chr1\nAT-G\n>seq2 mouse\nATTG\n";
+        let aligned = AlignedSequences::from_fasta(raw).unwrap();
+        let expected = AlignedSequences::new(vec![
+            ("seq1".to_string(), "AT-G".to_string()),
+            ("seq2".to_string(), "ATTG".to_string()),
+        ])
+        .unwrap();
+
+        assert_eq!(aligned.to_fasta(), raw);
+        assert_eq!(
+            AlignedSequences::from_clustal(&aligned.to_clustal()),
+            Ok(expected)
+        );
+        assert_eq!(
+            aligned.to_stockholm(),
+            "# STOCKHOLM 1.0\n\nseq1  AT-G\nseq2  ATTG\n//\n"
+        );
+    }
+
+    #[test]
+    fn names_multiple_alignments() {
+        let seqs: Vec<DNASequence> = ["ATATCCG", "TCCG", "ATGTACTG"]
+            .iter()
+            .map(|raw| raw.parse().unwrap())
+            .collect();
+        let alignment = MultipleAligner::new(MatchMismatch::new(0, -1), 1).progressive(&seqs);
+        let names: Vec<String> = ["a", "b", "c"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        let aligned = AlignedSequences::from_alignment(names.clone(), &alignment).unwrap();
+        assert_eq!(aligned.sequences::<Dna>().unwrap(), alignment.rows);
+        assert_eq!(
+            AlignedSequences::from_clustal(&aligned.to_clustal()),
+            Ok(aligned)
+        );
+        assert_eq!(
+            AlignedSequences::from_alignment(names[..2].to_vec(), &alignment),
+            Err(Error::WrongNumberOfNames {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
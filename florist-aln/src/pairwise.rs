@@ -0,0 +1,160 @@
+//! A pairwise alignment shown as BLAST shows it.
+//!
+//! Each block has the first sequence's row, a line marking the columns, and
+//! the second sequence's row, with the positions of the first and last
+//! symbols on each row counted from one.
+//!
+//! ```text
+//! Query  1   PLEASANTLY  10
+//!              |  || ||
+//! Sbjct  1   -ME--AN-LY  6
+//! ```
+use std::fmt::Write;
+
+use florist_align::{Alignment, Scoring, GAP};
+
+/// How to show pairwise alignments.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PairwiseView {
+    /// The name before each row of the first sequence.
+    pub first_name: String,
+
+    /// The name before each row of the second sequence.
+    pub second_name: String,
+
+    /// The number of columns per block.
+    pub width: usize,
+}
+
+/// Names the sequences `Query` and `Sbjct`, with 60 columns per block.
+impl Default for PairwiseView {
+    fn default() -> Self {
+        Self {
+            first_name: "Query".to_string(),
+            second_name: "Sbjct".to_string(),
+            width: 60,
+        }
+    }
+}
+
+impl PairwiseView {
+    /// Shows the alignment, marking identical columns with `|`.
+    pub fn render(&self, alignment: &Alignment) -> String {
+        self.render_with(alignment, |x, y| if x == y { '|' } else { ' ' })
+    }
+
+    /// Shows the alignment, marking identical columns with `|` and other
+    /// pairs the scoring scheme scores above zero with `+`, as BLAST does
+    /// for proteins.
+    pub fn render_scored<S: Scoring>(&self, alignment: &Alignment, scoring: &S) -> String {
+        self.render_with(alignment, |x, y| match (x, y) {
+            _ if x == y => '|',
+            (GAP, _) | (_, GAP) => ' ',
+            _ if scoring.score(x, y) > 0 => '+',
+            _ => ' ',
+        })
+    }
+
+    fn render_with<F: Fn(char, char) -> char>(&self, alignment: &Alignment, marker: F) -> String {
+        let name_width = self.first_name.len().max(self.second_name.len());
+        let position_width = alignment
+            .first_range
+            .end
+            .max(alignment.second_range.end)
+            .to_string()
+            .len();
+        let indent = name_width + position_width + 4;
+
+        let first: Vec<char> = alignment.first.chars().collect();
+        let second: Vec<char> = alignment.second.chars().collect();
+        // the number of symbols of each sequence before the block
+        let (mut first_pos, mut second_pos) =
+            (alignment.first_range.start, alignment.second_range.start);
+
+        let mut blocks = Vec::new();
+        for (first, second) in first
+            .chunks(self.width.max(1))
+            .zip(second.chunks(self.width.max(1)))
+        {
+            let markers: String = first
+                .iter()
+                .zip(second)
+                .map(|(x, y)| marker(*x, *y))
+                .collect();
+
+            let row = |name: &str, symbols: &[char], pos: &mut usize| {
+                let count = symbols.iter().filter(|symbol| **symbol != GAP).count();
+                // a row of nothing but gaps has no symbols to count from
+                let start = if count == 0 { *pos } else { *pos + 1 };
+                *pos += count;
+                let symbols: String = symbols.iter().collect();
+                format!(
+                    "{:<name_width$}  {:<position_width$}  {}  {}",
+                    name, start, symbols, pos
+                )
+            };
+
+            let mut block = String::new();
+            // writing to a String cannot fail
+            writeln!(block, "{}", row(&self.first_name, first, &mut first_pos)).unwrap();
+            writeln!(block, "{:indent$}{}", "", markers.trim_end()).unwrap();
+            writeln!(block, "{}", row(&self.second_name, second, &mut second_pos)).unwrap();
+            blocks.push(block);
+        }
+
+        blocks.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_align::{Aligner, GapPenalty, Mode};
+    use florist_core::{ProteinSequence, ScoringMatrix};
+
+    use super::*;
+
+    #[test]
+    fn shows_blocks() {
+        let aligner = Aligner::levenshtein();
+        let alignment = aligner.align(
+            &ProteinSequence::from_str("PLEASANTLY").unwrap(),
+            &ProteinSequence::from_str("MEANLY").unwrap(),
+        );
+        assert_eq!(
+            PairwiseView::default().render(&alignment),
+            "Query  1   PLEASANTLY  10
+             |  || ||
+Sbjct  1   -ME--AN-LY  6
+"
+        );
+
+        // blocks count on from the part of each sequence aligned
+        let local = Aligner::new(
+            Mode::Local,
+            ScoringMatrix::blosum62(),
+            GapPenalty::affine(11, 1),
+        );
+        let alignment = local.align(
+            &ProteinSequence::from_str("PLEASANTLY").unwrap(),
+            &ProteinSequence::from_str("MEANLY").unwrap(),
+        );
+        let view = PairwiseView {
+            first_name: "first".to_string(),
+            second_name: "second".to_string(),
+            width: 3,
+        };
+        assert_eq!(
+            view.render_scored(&alignment, ScoringMatrix::blosum62()),
+            "first   2  LEA  4
+           +||
+second  1  MEA  3
+
+first   5  S  5
+           +
+second  4  N  4
+"
+        );
+    }
+}
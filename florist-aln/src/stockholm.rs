@@ -0,0 +1,39 @@
+//! Stockholm files, as used by Pfam and Rfam.
+//!
+//! The alignment is written in a single block between the `# STOCKHOLM 1.0`
+//! header and the `//` terminator, each row on one line after its name.
+use std::fmt::Write;
+
+use crate::AlignedSequences;
+
+pub(crate) fn write(aligned: &AlignedSequences) -> String {
+    let name_width = aligned.name_width();
+    let mut output = String::from("# STOCKHOLM 1.0\n\n");
+
+    for (name, row) in aligned.named_rows() {
+        // writing to a String cannot fail
+        writeln!(output, "{:<name_width$}  {}", name, row).unwrap();
+    }
+
+    output.push_str("//\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_one_block() {
+        let aligned = AlignedSequences::new(vec![
+            ("seq1".to_string(), "ATAT-CCG".to_string()),
+            ("longer".to_string(), "-T---CCG".to_string()),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            aligned.to_stockholm(),
+            "# STOCKHOLM 1.0\n\nseq1    ATAT-CCG\nlonger  -T---CCG\n//\n"
+        );
+    }
+}
@@ -1,9 +1,26 @@
-use std::{marker::PhantomData, path::PathBuf, str::FromStr};
+use std::{
+    io::{ErrorKind, Read, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
-use anyhow::{Context, Result};
-use clap::{Args, CommandFactory, Parser, Subcommand};
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{generate, shells::Zsh};
-use florist_plumbing::Problem;
+use florist_core::GeneticCode;
+use florist_plumbing::{generate_seeded, Checker, Descriptor, OutputOrder, Problem, Tolerance};
+use florist_solver::{find_by_id, find_by_path, florist_registry, REGISTRY};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    motifs::Motifs,
+    orfs::Orfs,
+    profile::{self, format_bytes, Profile},
+    translate::Translate,
+};
 
 #[derive(Parser)]
 #[clap(name = "florist", version, max_term_width = 120)]
@@ -19,98 +36,762 @@ impl Cli {
     }
 }
 
-#[derive(Subcommand)]
-pub(crate) enum Commands {
-    GenerateCompletions(GenerateCompletions),
-    CountingDnaNucleotides(Solver<counting_dna_nucleotides::CountingDnaNucleotides>),
-    TranscribingDnaIntoRna(Solver<transcribing_dna_into_rna::TranscribingDnaIntoRna>),
-    ComplementingAStrandOfDna(Solver<complementing_a_strand_of_dna::ComplementingAStrandOfDna>),
-    RabbitsAndRecurrenceRelations(
-        Solver<rabbits_and_recurrence_relations::RabbitsAndRecurrenceRelations>,
-    ),
-    ComputingGcContent(Solver<computing_gc_content::ComputingGcContent>),
-    CountingPointMutations(Solver<counting_point_mutations::CountingPointMutations>),
-    EnumeratingGeneOrders(Solver<enumerating_gene_orders::EnumeratingGeneOrders>),
-    EnumeratingKMersLexicographically(
-        Solver<enumerating_k_mers_lexicographically::EnumeratingKMersLexicographically>,
-    ),
-    MendelsFirstLaw(Solver<mendels_first_law::MendelsFirstLaw>),
-    TranslatingRnaIntoProtein(Solver<translating_rna_into_protein::TranslatingRnaIntoProtein>),
-    FindingAMotifInDna(Solver<finding_a_motif_in_dna::FindingAMotifInDna>),
-    CalculatingExpectedOffspring(
-        Solver<calculating_expected_offspring::CalculatingExpectedOffspring>,
-    ),
-    LocatingRestrictionSites(Solver<locating_restriction_sites::LocatingRestrictionSites>),
-    MortalFibonacciRabbits(Solver<mortal_fibonacci_rabbits::MortalFibonacciRabbits>),
-    InferringMrnaFromProtein(Solver<inferring_mrna_from_protein::InferringMrnaFromProtein>),
-    IndependentAlleles(Solver<independent_alleles::IndependentAlleles>),
-    OverlapGraphs(Solver<overlap_graphs::OverlapGraphs>),
-    ConsensusAndProfile(Solver<consensus_and_profile::ConsensusAndProfile>),
-    OpenReadingFrames(Solver<open_reading_frames::OpenReadingFrames>),
-    RnaSplicing(Solver<rna_splicing::RnaSplicing>),
-    TransitionsAndTransversions(Solver<transitions_and_transversions::TransitionsAndTransversions>),
-    EnumeratingOrientedGeneOrderings(
-        Solver<enumerating_oriented_gene_orderings::EnumeratingOrientedGeneOrderings>,
-    ),
-}
-
-impl Commands {
-    fn run(&self) -> Result<()> {
+/// The arguments of a registered problem's subcommand, which take a
+/// `--table` if the problem is registered with `genetic_code`.
+macro_rules! solver_args {
+    ($solver:ty) => {
+        Solver<$solver>
+    };
+    ($solver:ty, genetic_code) => {
+        TranslatingSolver<$solver>
+    };
+}
+
+/// Implements [Translates] for the problems registered with `genetic_code`.
+macro_rules! translates {
+    ($solver:ty) => {};
+    ($solver:ty, genetic_code) => {
+        impl Translates for $solver {
+            fn solve_with_code(
+                input: Self::Input,
+                code: &GeneticCode,
+            ) -> Result<Self::Output, Self::Error> {
+                // the inherent method, which takes precedence
+                <$solver>::solve_with_code(input, code)
+            }
+        }
+    };
+}
+
+/// Runs the solver, with the genetic code if there is one and the problem is
+/// registered with `genetic_code`.
+macro_rules! run_with_table {
+    ($solver:expr, $table:expr, $desc:expr) => {
+        match $table {
+            Some(_) => bail!(
+                "{} does not translate codons, so takes no --table",
+                $desc.id
+            ),
+            None => $solver.run(),
+        }
+    };
+    ($solver:expr, $table:expr, $desc:expr, genetic_code) => {
+        TranslatingSolver::new($solver, $table).run()
+    };
+}
+
+macro_rules! commands {
+    ($(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => {
+        #[derive(Subcommand)]
+        pub(crate) enum Commands {
+            GenerateCompletions(GenerateCompletions),
+            Batch(Batch),
+            Check(Check),
+            Generate(Generate),
+            List(List),
+            Motifs(Motifs),
+            Orfs(Orfs),
+            Sample(Sample),
+            Solve(Solve),
+            Translate(Translate),
+            $(
+                #[clap(about = ($desc).title)]
+                $variant(solver_args!($solver $(, $marker)?)),
+            )+
+        }
+
+        $(
+            translates!($solver $(, $marker)?);
+        )+
+
+        impl Commands {
+            fn run(&self) -> Result<()> {
+                match self {
+                    Self::GenerateCompletions(cmd) => cmd.run(),
+                    Self::Batch(cmd) => cmd.run(),
+                    Self::Check(cmd) => cmd.run(),
+                    Self::Generate(cmd) => cmd.run(),
+                    Self::List(cmd) => cmd.run(),
+                    Self::Motifs(cmd) => cmd.run(),
+                    Self::Orfs(cmd) => cmd.run(),
+                    Self::Sample(cmd) => cmd.run(),
+                    Self::Solve(cmd) => cmd.run(),
+                    Self::Translate(cmd) => cmd.run(),
+                    $(
+                        Self::$variant(cmd) => cmd.run(),
+                    )+
+                }
+            }
+        }
+
+        /// Runs the solver for the given registered problem.
+        fn solve_as(
+            desc: &Descriptor,
+            input: Option<PathBuf>,
+            output: Option<PathBuf>,
+            format: Format,
+            time: bool,
+            table: Option<GeneticCode>,
+        ) -> Result<()> {
+            $(
+                if desc.id == ($desc).id {
+                    let solver = Solver::<$solver>::new(input, output, format, time);
+                    return run_with_table!(solver, table, desc $(, $marker)?);
+                }
+            )+
+
+            bail!("No solver registered for problem ID: {}", desc.id)
+        }
+
+        /// The output order declared by the given registered problem.
+        fn output_order_of(desc: &Descriptor) -> OutputOrder {
+            $(
+                if desc.id == ($desc).id {
+                    return <$solver as Problem>::OUTPUT_ORDER;
+                }
+            )+
+
+            OutputOrder::default()
+        }
+
+        /// The sample declared by the given registered problem, if any.
+        fn sample_of(desc: &Descriptor) -> Option<florist_plumbing::Sample> {
+            $(
+                if desc.id == ($desc).id {
+                    return <$solver as Problem>::SAMPLE;
+                }
+            )+
+
+            None
+        }
+
+        /// Generates a dataset for the given registered problem, if it knows
+        /// how to make one.
+        fn generate_as(desc: &Descriptor, seed: u64, size: usize) -> Option<String> {
+            $(
+                if desc.id == ($desc).id {
+                    return generate_seeded::<$solver>(seed, size);
+                }
+            )+
+
+            None
+        }
+
+        /// Solves the raw dataset with the solver for the given registered
+        /// problem, returning the formatted output and how long each phase
+        /// took.
+        fn solve_profiled_as(
+            desc: &Descriptor,
+            input_raw: &str,
+            format: Format,
+        ) -> Result<(String, Profile)> {
+            $(
+                if desc.id == ($desc).id {
+                    return Solver::<$solver>::solve_profiled(input_raw, format);
+                }
+            )+
+
+            bail!("No solver registered for problem ID: {}", desc.id)
+        }
+    };
+}
+
+florist_registry!(commands);
+
+/// Solves the raw dataset with the solver for the given registered problem,
+/// returning the formatted output.
+fn solve_raw_as(desc: &Descriptor, input_raw: &str, format: Format) -> Result<String> {
+    solve_profiled_as(desc, input_raw, format).map(|(output, _)| output)
+}
+
+/// How solutions are written.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub(crate) enum Format {
+    /// The plain text answer Rosalind expects
+    #[default]
+    Text,
+
+    /// Structured JSON, for consumption by other tools
+    Json,
+}
+
+impl Format {
+    /// The extension for files holding solutions in this format.
+    fn extension(&self) -> &'static str {
         match self {
-            Self::GenerateCompletions(cmd) => cmd.run(),
-            Self::CountingDnaNucleotides(cmd) => cmd.run(),
-            Self::TranscribingDnaIntoRna(cmd) => cmd.run(),
-            Self::ComplementingAStrandOfDna(cmd) => cmd.run(),
-            Self::RabbitsAndRecurrenceRelations(cmd) => cmd.run(),
-            Self::ComputingGcContent(cmd) => cmd.run(),
-            Self::CountingPointMutations(cmd) => cmd.run(),
-            Self::EnumeratingGeneOrders(cmd) => cmd.run(),
-            Self::EnumeratingKMersLexicographically(cmd) => cmd.run(),
-            Self::MendelsFirstLaw(cmd) => cmd.run(),
-            Self::TranslatingRnaIntoProtein(cmd) => cmd.run(),
-            Self::FindingAMotifInDna(cmd) => cmd.run(),
-            Self::CalculatingExpectedOffspring(cmd) => cmd.run(),
-            Self::LocatingRestrictionSites(cmd) => cmd.run(),
-            Self::MortalFibonacciRabbits(cmd) => cmd.run(),
-            Self::InferringMrnaFromProtein(cmd) => cmd.run(),
-            Self::IndependentAlleles(cmd) => cmd.run(),
-            Self::OverlapGraphs(cmd) => cmd.run(),
-            Self::ConsensusAndProfile(cmd) => cmd.run(),
-            Self::OpenReadingFrames(cmd) => cmd.run(),
-            Self::RnaSplicing(cmd) => cmd.run(),
-            Self::TransitionsAndTransversions(cmd) => cmd.run(),
-            Self::EnumeratingOrientedGeneOrderings(cmd) => cmd.run(),
+            Self::Text => "out",
+            Self::Json => "json",
         }
     }
 }
 
 #[derive(Args)]
-pub(crate) struct Solver<T> {
-    /// The path to the input dataset
-    input: PathBuf,
+pub(crate) struct Solver<T: Problem> {
+    /// The path to the input dataset, reads from stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Write the solution to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// The format to write the solution in
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Report how long each phase of solving took to stderr
+    #[clap(long)]
+    time: bool,
 
     #[clap(skip)]
     _phantom: PhantomData<T>,
 }
 
+impl<T: Problem> Solver<T> {
+    pub fn new(
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+        format: Format,
+        time: bool,
+    ) -> Self {
+        Self {
+            input,
+            output,
+            format,
+            time,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<T> Solver<T>
 where
     T: Problem,
+    <T as Problem>::Output: Serialize,
     <T as Problem>::Error: Into<anyhow::Error>,
     <<T as Problem>::Input as FromStr>::Err: Into<anyhow::Error>,
 {
     pub fn run(&self) -> Result<()> {
-        let input_raw = std::fs::read_to_string(&self.input).context("Failed to read input")?;
+        self.run_with(Self::solve_profiled)
+    }
+
+    /// Like [Solver::run], solving the raw dataset with `solve`.
+    fn run_with<F>(&self, solve: F) -> Result<()>
+    where
+        F: FnOnce(&str, Format) -> Result<(String, Profile)>,
+    {
+        let (input_raw, read) = profile::measure(|| read_input(self.input.as_deref()));
+        let (output, profile) = solve(&input_raw?, self.format)?;
+        write_output(self.output.as_deref(), &output)?;
+
+        if self.time {
+            eprint!("{}", Profile { read, ..profile });
+        }
+
+        Ok(())
+    }
+
+    /// Parses and solves the given raw dataset, returning the output in the
+    /// given format along with how long each phase took.
+    pub fn solve_profiled(input_raw: &str, format: Format) -> Result<(String, Profile)> {
+        Self::solve_profiled_with(input_raw, format, T::solve)
+    }
+
+    /// Like [Solver::solve_profiled], solving the parsed input with `solve`.
+    fn solve_profiled_with<F>(
+        input_raw: &str,
+        format: Format,
+        solve: F,
+    ) -> Result<(String, Profile)>
+    where
+        F: FnOnce(T::Input) -> Result<T::Output, T::Error>,
+    {
         // yeah, this is nuts, maybe revisit what these bounds have to actually be
-        let input = T::input_from(input_raw.trim())
+        let (input, parse) = profile::measure(|| T::input_from(input_raw.trim()));
+        let input = input
             .map_err(Into::<anyhow::Error>::into)
             .context("Failed to parse input")?;
-        let output = T::solve(input)
+
+        let (output, solve) = profile::measure(|| solve(input));
+        let output = output
             .map_err(Into::<anyhow::Error>::into)
             .context("Failed to solve")?;
-        println!("{}", output);
+
+        let (formatted, format) = profile::measure(|| match format {
+            Format::Text => Ok(output.to_string()),
+            Format::Json => {
+                serde_json::to_string_pretty(&output).context("Failed to serialize solution")
+            }
+        });
+
+        Ok((
+            formatted?,
+            Profile {
+                parse,
+                solve,
+                format,
+                ..Default::default()
+            },
+        ))
+    }
+}
+
+/// Problems that translate codons, so can be solved with any genetic code
+/// instead of the standard one. Implemented for the problems registered with
+/// `genetic_code`.
+pub(crate) trait Translates: Problem {
+    fn solve_with_code(input: Self::Input, code: &GeneticCode)
+        -> Result<Self::Output, Self::Error>;
+}
+
+/// The arguments of a problem that translates codons, which can take a
+/// genetic code.
+#[derive(Args)]
+pub(crate) struct TranslatingSolver<T: Problem> {
+    #[clap(flatten)]
+    solver: Solver<T>,
+
+    /// The NCBI genetic code to translate with, e.g. 2 for vertebrate
+    /// mitochondria or 11 for bacteria [default: 1, starting only at ATG]
+    #[clap(long, value_name = "ID")]
+    table: Option<GeneticCode>,
+}
+
+impl<T> TranslatingSolver<T>
+where
+    T: Translates,
+    <T as Problem>::Output: Serialize,
+    <T as Problem>::Error: Into<anyhow::Error>,
+    <<T as Problem>::Input as FromStr>::Err: Into<anyhow::Error>,
+{
+    pub fn new(solver: Solver<T>, table: Option<GeneticCode>) -> Self {
+        Self { solver, table }
+    }
+
+    pub fn run(&self) -> Result<()> {
+        match &self.table {
+            Some(code) => self.solver.run_with(|input_raw, format| {
+                Solver::<T>::solve_profiled_with(input_raw, format, |input| {
+                    T::solve_with_code(input, code)
+                })
+            }),
+            None => self.solver.run(),
+        }
+    }
+}
+
+/// Solve a dataset, detecting the problem from its `rosalind_<id>.txt` name
+#[derive(Debug, Args)]
+pub struct Solve {
+    /// The Rosalind problem ID (e.g. GC), instead of detecting it
+    #[clap(long)]
+    id: Option<String>,
+
+    /// The path to the input dataset, reads from stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Write the solution to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// The format to write the solution in
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Report how long each phase of solving took to stderr
+    #[clap(long)]
+    time: bool,
+
+    /// The NCBI genetic code to translate with, for the problems that
+    /// translate codons [default: 1, starting only at ATG]
+    #[clap(long, value_name = "ID")]
+    table: Option<GeneticCode>,
+}
+
+impl Solve {
+    fn run(&self) -> Result<()> {
+        let desc = resolve_problem(self.id.as_deref(), self.input.as_deref())?;
+        solve_as(
+            desc,
+            self.input.clone(),
+            self.output.clone(),
+            self.format,
+            self.time,
+            self.table,
+        )
+    }
+}
+
+/// Solve a dataset and check the solution against an expected answer
+#[derive(Debug, Args)]
+pub struct Check {
+    /// The Rosalind problem ID (e.g. GC), instead of detecting it
+    #[clap(long)]
+    id: Option<String>,
+
+    /// The path to the input dataset
+    input: PathBuf,
+
+    /// The path to the expected solution
+    expected: PathBuf,
+
+    /// Numeric values may differ by at most this much [default: 0.001]
+    #[clap(long, conflicts_with = "rel-tol")]
+    abs_tol: Option<f64>,
+
+    /// Numeric values may differ by at most this fraction of their magnitude
+    #[clap(long)]
+    rel_tol: Option<f64>,
+
+    /// Ignore the order of lines, even if the problem's output is ordered
+    #[clap(long)]
+    unordered: bool,
+}
+
+impl Check {
+    fn run(&self) -> Result<()> {
+        let desc = resolve_problem(self.id.as_deref(), Some(&self.input))?;
+        let input_raw = read_input(Some(&self.input))?;
+        let expected =
+            std::fs::read_to_string(&self.expected).context("Failed to read expected solution")?;
+        let output = solve_raw_as(desc, &input_raw, Format::Text)?;
+
+        let mut checker = Checker::new(output_order_of(desc), Tolerance::default());
+        if self.unordered {
+            checker.order = OutputOrder::Unordered;
+        }
+        if let Some(tol) = self.abs_tol {
+            checker.tolerance = Tolerance::Absolute(tol);
+        }
+        if let Some(tol) = self.rel_tol {
+            checker.tolerance = Tolerance::Relative(tol);
+        }
+
+        match checker.check(&output, &expected) {
+            Ok(_) => {
+                println!("{} ({}): ok", desc.id, desc.title);
+                Ok(())
+            }
+            Err(mismatch) => {
+                eprint!("{}", mismatch);
+                bail!("{} ({}): solution does not match", desc.id, desc.title)
+            }
+        }
+    }
+}
+
+/// Solve the sample dataset from a problem's statement
+#[derive(Debug, Args)]
+pub struct Sample {
+    /// The Rosalind problem ID (e.g. GC) or subcommand name of the problem
+    problem: String,
+
+    /// Only print the sample dataset, e.g. to pipe it into another command
+    #[clap(long)]
+    dataset: bool,
+}
+
+impl Sample {
+    fn run(&self) -> Result<()> {
+        let desc = find_problem(&self.problem)?;
+        let sample = sample_of(desc)
+            .ok_or_else(|| anyhow!("{} ({}) has no sample dataset", desc.id, desc.title))?;
+
+        if self.dataset {
+            return write_output(None, sample.input);
+        }
+
+        let output = solve_raw_as(desc, sample.input, Format::Text)?;
+        println!("Sample dataset:\n{}\n", sample.input);
+        println!("Sample output:\n{}\n", sample.output);
+        println!("Solution:\n{}\n", output);
+
+        let checker = Checker::new(output_order_of(desc), Tolerance::default());
+        match checker.check(&output, sample.output) {
+            Ok(_) => {
+                println!("{} ({}): ok", desc.id, desc.title);
+                Ok(())
+            }
+            Err(mismatch) => {
+                eprint!("{}", mismatch);
+                bail!("{} ({}): solution does not match", desc.id, desc.title)
+            }
+        }
+    }
+}
+
+/// Generate a random dataset for a problem
+#[derive(Debug, Args)]
+pub struct Generate {
+    /// The Rosalind problem ID (e.g. GC) or subcommand name of the problem
+    problem: String,
+
+    /// Roughly the length of the main sequence(s), or the main parameter for
+    /// problems without sequences
+    #[clap(long, default_value_t = 100)]
+    size: usize,
+
+    /// Seed for the random dataset, so it can be reproduced [default: random]
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// Write the dataset to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+impl Generate {
+    fn run(&self) -> Result<()> {
+        let desc = find_problem(&self.problem)?;
+        let seed = match self.seed {
+            Some(seed) => seed,
+            None => {
+                let seed = rand::random();
+                // otherwise there would be no way to reproduce the dataset
+                eprintln!("seed: {}", seed);
+                seed
+            }
+        };
+
+        let dataset = generate_as(desc, seed, self.size).ok_or_else(|| {
+            anyhow!(
+                "{} ({}) does not support generating datasets",
+                desc.id,
+                desc.title
+            )
+        })?;
+
+        write_output(self.output.as_deref(), dataset.trim_end())
+    }
+}
+
+/// Finds the problem by its ID or subcommand name.
+fn find_problem(problem: &str) -> Result<&'static Descriptor> {
+    find_by_id(problem)
+        .or_else(|| REGISTRY.iter().find(|d| d.slug == problem))
+        .ok_or_else(|| anyhow!("Unknown problem: {}", problem))
+}
+
+/// Finds the problem by its ID if one was given, or from the name of the
+/// input dataset otherwise.
+fn resolve_problem(id: Option<&str>, input: Option<&Path>) -> Result<&'static Descriptor> {
+    match (id, input) {
+        (Some(id), _) => find_by_id(id).ok_or_else(|| anyhow!("Unknown problem ID: {}", id)),
+        (None, Some(input)) if !is_stdio(input) => find_by_path(input).ok_or_else(|| {
+            anyhow!(
+                "Could not determine the problem from {:?}, try passing --id",
+                input
+            )
+        }),
+        (None, _) => bail!("Reading from stdin requires passing --id"),
+    }
+}
+
+/// Solve every `rosalind_*.txt` dataset in a directory in parallel
+#[derive(Debug, Args)]
+pub struct Batch {
+    /// The directory containing the input datasets
+    dir: PathBuf,
+
+    /// Where to write the solutions, defaults to the input directory. Each is
+    /// named after its dataset, so `rosalind_gc(1).txt` is solved to
+    /// `rosalind_gc(1).out` (or `.json`)
+    #[clap(short, long)]
+    output_dir: Option<PathBuf>,
+
+    /// The format to write the solutions in
+    #[clap(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Report how long each phase of solving took for every dataset, solving
+    /// them one at a time so the measurements don't interfere
+    #[clap(long)]
+    time: bool,
+}
+
+/// The outcome of solving a single dataset in a batch.
+#[derive(Debug)]
+struct BatchResult {
+    file: String,
+    id: Option<&'static str>,
+    outcome: Result<PathBuf>,
+    elapsed: Duration,
+
+    /// How long each phase took, if solving got that far.
+    profile: Option<Profile>,
+}
+
+impl Batch {
+    fn run(&self) -> Result<()> {
+        let output_dir = self.output_dir.as_ref().unwrap_or(&self.dir);
+        std::fs::create_dir_all(output_dir).context("Failed to create output directory")?;
+
+        let mut inputs = std::fs::read_dir(&self.dir)
+            .context("Failed to read input directory")?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read input directory")?;
+        inputs.retain(|p| {
+            p.is_file()
+                && p.extension().map(|e| e == "txt").unwrap_or(false)
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|n| n.starts_with("rosalind_"))
+                    .unwrap_or(false)
+        });
+        inputs.sort();
+
+        if inputs.is_empty() {
+            bail!("No rosalind_*.txt datasets found in {:?}", self.dir);
+        }
+
+        let solve = |input: &PathBuf| Self::solve_one(input, output_dir, self.format);
+        let start = Instant::now();
+        let results: Vec<_> = if self.time {
+            inputs.iter().map(solve).collect()
+        } else {
+            inputs.par_iter().map(solve).collect()
+        };
+        let total = start.elapsed();
+
+        let failures = results.iter().filter(|r| r.outcome.is_err()).count();
+        let show_peak = results
+            .iter()
+            .any(|r| r.profile.and_then(|p| p.peak_alloc()).is_some());
+
+        print!("{:<6} {:<32} {:<8} {:>12}", "ID", "FILE", "STATUS", "TIME");
+        if self.time {
+            for (name, _) in Profile::default().phases() {
+                print!(" {:>12}", name.to_ascii_uppercase());
+            }
+            if show_peak {
+                print!(" {:>12}", "PEAK ALLOC");
+            }
+        }
+        println!();
+
+        for res in results.iter() {
+            let status = if res.outcome.is_ok() { "ok" } else { "FAILED" };
+            print!(
+                "{:<6} {:<32} {:<8} {:>12}",
+                res.id.unwrap_or("?"),
+                res.file,
+                status,
+                format!("{:.2?}", res.elapsed),
+            );
+            if self.time {
+                let profile = res.profile.unwrap_or_default();
+                for (_, phase) in profile.phases() {
+                    print!(" {:>12}", format!("{:.2?}", phase.elapsed));
+                }
+                if show_peak {
+                    let peak = profile.peak_alloc().map(format_bytes);
+                    print!(" {:>12}", peak.unwrap_or_else(|| "-".into()));
+                }
+            }
+            println!();
+        }
+
+        for res in results.iter() {
+            if let Err(e) = &res.outcome {
+                eprintln!("{}: {:#}", res.file, e);
+            }
+        }
+
+        println!(
+            "\n{} succeeded, {} failed in {:.2?}",
+            results.len() - failures,
+            failures,
+            total
+        );
+
+        if failures > 0 {
+            bail!("{} of {} datasets failed", failures, results.len());
+        }
+
         Ok(())
     }
+
+    fn solve_one(input: &Path, output_dir: &Path, format: Format) -> BatchResult {
+        let start = Instant::now();
+        let desc = find_by_path(input);
+        let mut profile = None;
+        let outcome = desc
+            .ok_or_else(|| anyhow!("No solver registered for this dataset"))
+            .and_then(|desc| {
+                let (input_raw, read) = profile::measure(|| std::fs::read_to_string(input));
+                let input_raw = input_raw.context("Failed to read input")?;
+                let (output, solved) = solve_profiled_as(desc, &input_raw, format)?;
+                profile = Some(Profile { read, ..solved });
+
+                // several datasets can be for the same problem, so the
+                // solutions are named after the datasets rather than the ID
+                let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+                let dest = output_dir.join(format!("{}.{}", stem, format.extension()));
+                std::fs::write(&dest, output + "\n").context("Failed to write output")?;
+                Ok(dest)
+            });
+
+        BatchResult {
+            file: input
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            id: desc.map(|d| d.id),
+            outcome,
+            elapsed: start.elapsed(),
+            profile,
+        }
+    }
+}
+
+/// List every supported problem
+#[derive(Debug, Args)]
+pub struct List;
+
+impl List {
+    fn run(&self) -> Result<()> {
+        for desc in REGISTRY {
+            println!("{:<6} {:<40} {}", desc.id, desc.title, desc.slug);
+        }
+        Ok(())
+    }
+}
+
+/// Whether the given path is `-`, meaning stdin or stdout.
+fn is_stdio(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Reads the input dataset from the given path, or from stdin if there is no
+/// path or the path is `-`.
+pub(crate) fn read_input(path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) if !is_stdio(path) => {
+            std::fs::read_to_string(path).context("Failed to read input")
+        }
+        _ => {
+            let mut buf = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buf)
+                .context("Failed to read input from stdin")?;
+            Ok(buf)
+        }
+    }
+}
+
+/// Writes the solution to the given path, or to stdout if there is no path or
+/// the path is `-`.
+pub(crate) fn write_output(path: Option<&Path>, output: &str) -> Result<()> {
+    match path {
+        Some(path) if !is_stdio(path) => {
+            std::fs::write(path, format!("{}\n", output)).context("Failed to write output")
+        }
+        _ => match writeln!(std::io::stdout().lock(), "{}", output) {
+            // whatever we were piped into stopped listening, which is fine
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+            res => res.context("Failed to write output"),
+        },
+    }
 }
 
 /// Generate zsh completions
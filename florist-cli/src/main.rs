@@ -1,6 +1,10 @@
 use anyhow::Result;
 
 mod cli;
+mod motifs;
+mod orfs;
+mod profile;
+mod translate;
 
 fn main() -> Result<()> {
     cli::Cli::run()
@@ -0,0 +1,78 @@
+use std::{fmt::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Args;
+use florist_core::{AmbiguousDNASequence, Distance};
+use florist_fasta::OrderedMultiFasta;
+
+use crate::cli::{read_input, write_output};
+
+/// Find a motif on both strands of every sequence in a FASTA file, allowing
+/// mismatches
+#[derive(Debug, Args)]
+pub struct Motifs {
+    /// The motif to find, which may contain IUPAC ambiguity codes
+    motif: String,
+
+    /// The path to the FASTA file, reads from stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Write the matches to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// The most substitutions, or edits, a match may have
+    #[clap(short = 'k', long, default_value_t = 0)]
+    max_mismatches: u64,
+
+    /// Allow insertions and deletions as well as substitutions
+    #[clap(long)]
+    edits: bool,
+}
+
+impl Motifs {
+    pub(crate) fn run(&self) -> Result<()> {
+        let motif: AmbiguousDNASequence = self.motif.parse().context("Invalid motif")?;
+        let fasta: OrderedMultiFasta = read_input(self.input.as_deref())?
+            .parse()
+            .context("Failed to parse FASTA")?;
+        let distance = if self.edits {
+            Distance::Edit
+        } else {
+            Distance::Hamming
+        };
+
+        let mut output = String::from("seqid\tstart\tend\tstrand\tmismatches\tmatch\n");
+        for (description, raw) in fasta.iter() {
+            let seq: AmbiguousDNASequence = raw
+                .parse()
+                .with_context(|| format!("Invalid sequence: {}", description))?;
+            // the ID is the first word of the description
+            let seqid = description.split_whitespace().next().unwrap_or_default();
+
+            let forward = seq.as_slice();
+            let mut hits = forward.approximate_motif_hits(&motif, self.max_mismatches, distance);
+            hits.extend(forward.reverse_complement().approximate_motif_hits(
+                &motif,
+                self.max_mismatches,
+                distance,
+            ));
+            hits.sort_by_key(|hit| (hit.start(), hit.end(), hit.location.strand()));
+
+            for hit in hits {
+                writeln!(
+                    output,
+                    "{}\t{}\t{}\t{}\t{}\t{}",
+                    seqid,
+                    hit.start(),
+                    hit.end(),
+                    hit.location.strand(),
+                    hit.mismatches,
+                    hit.location
+                )?;
+            }
+        }
+
+        write_output(self.output.as_deref(), output.trim_end())
+    }
+}
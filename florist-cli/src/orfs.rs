@@ -0,0 +1,170 @@
+use std::{fmt::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use florist_core::{DNASequence, GeneticCode, Orf, OrfFinder, StartPolicy};
+use florist_fasta::OrderedMultiFasta;
+
+use crate::cli::{read_input, write_output};
+
+/// How ORFs are written.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub(crate) enum OrfFormat {
+    /// Tab-separated columns with a header, in 0-based, half-open coordinates
+    #[default]
+    Tsv,
+
+    /// GFF3 features, in 1-based, inclusive coordinates
+    Gff3,
+}
+
+/// Find the open reading frames on both strands of every sequence in a FASTA
+/// file
+#[derive(Debug, Args)]
+pub struct Orfs {
+    /// The path to the FASTA file, reads from stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Write the ORFs to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// The format to write the ORFs in
+    #[clap(long, value_enum, default_value_t)]
+    format: OrfFormat,
+
+    /// The fewest nucleotides an ORF may have, counting the stop codon
+    #[clap(long, default_value_t = 75)]
+    min_length: usize,
+
+    /// Report an ORF from every start codon, not just the first before each
+    /// stop
+    #[clap(long)]
+    nested: bool,
+
+    /// Also report ORFs running off the end of the sequence without a stop
+    #[clap(long)]
+    partial: bool,
+
+    /// The NCBI genetic code to translate with, e.g. 2 for vertebrate
+    /// mitochondria or 11 for bacteria [default: 1, starting only at ATG]
+    #[clap(long, value_name = "ID")]
+    table: Option<GeneticCode>,
+}
+
+impl Orfs {
+    pub(crate) fn run(&self) -> Result<()> {
+        let fasta: OrderedMultiFasta = read_input(self.input.as_deref())?
+            .parse()
+            .context("Failed to parse FASTA")?;
+
+        let mut finder = OrfFinder {
+            min_length: self.min_length,
+            partial: self.partial,
+            ..Default::default()
+        };
+        if self.nested {
+            finder.starts = StartPolicy::Nested;
+        }
+        if let Some(code) = self.table {
+            finder.genetic_code = code;
+        }
+
+        let mut output = match self.format {
+            OrfFormat::Tsv => "seqid\tstart\tend\tstrand\tframe\tlength\tpartial\tprotein\n".into(),
+            OrfFormat::Gff3 => "##gff-version 3\n".to_string(),
+        };
+
+        for (description, raw) in fasta.iter() {
+            let seq: DNASequence = raw
+                .parse()
+                .with_context(|| format!("Invalid sequence: {}", description))?;
+            // the ID is the first word of the description
+            let seqid = description.split_whitespace().next().unwrap_or_default();
+
+            for (i, orf) in finder.find(&seq).iter().enumerate() {
+                match self.format {
+                    OrfFormat::Tsv => write_tsv(&mut output, seqid, orf),
+                    OrfFormat::Gff3 => write_gff3(&mut output, seqid, i + 1, orf),
+                }?;
+            }
+        }
+
+        write_output(self.output.as_deref(), output.trim_end())
+    }
+}
+
+fn write_tsv(output: &mut String, seqid: &str, orf: &Orf) -> std::fmt::Result {
+    writeln!(
+        output,
+        "{}\t{}\t{}\t{}\t{}{}\t{}\t{}\t{}",
+        seqid,
+        orf.start,
+        orf.end,
+        orf.strand,
+        orf.strand,
+        orf.frame + 1,
+        orf.len(),
+        orf.partial,
+        orf.protein
+    )
+}
+
+fn write_gff3(output: &mut String, seqid: &str, n: usize, orf: &Orf) -> std::fmt::Result {
+    let seqid = escape_gff3(seqid);
+    write!(
+        output,
+        "{}\tflorist\tORF\t{}\t{}\t.\t{}\t.\tID={}_orf{}",
+        seqid,
+        orf.start + 1,
+        orf.end,
+        orf.strand,
+        seqid,
+        n
+    )?;
+
+    if orf.partial {
+        output.push_str(";partial=true");
+    }
+
+    writeln!(output)
+}
+
+/// Percent-encodes every byte GFF3 does not allow in a seqid, which covers
+/// the `;`, `=`, `,` and whitespace that would break the attributes too.
+fn escape_gff3(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b".:^*$@!+_?-|".contains(&byte) {
+            escaped.push(byte as char);
+        } else {
+            // writing to a String cannot fail
+            write!(escaped, "%{:02X}", byte).unwrap();
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_gff3_ids() {
+        let seq: DNASequence = "ATGAAATAG".parse().unwrap();
+        let orf = &OrfFinder::default().find(&seq)[0];
+
+        let mut output = String::new();
+        write_gff3(&mut output, "chr1;note=a,b%\tc", 1, orf).unwrap();
+        assert_eq!(
+            output,
+            "chr1%3Bnote%3Da%2Cb%25%09c\tflorist\tORF\t1\t9\t.\t+\t.\tID=chr1%3Bnote%3Da%2Cb%25%09c_orf1\n"
+        );
+
+        let mut output = String::new();
+        write_gff3(&mut output, "Rosalind_99|x.1", 2, orf).unwrap();
+        assert!(output.starts_with("Rosalind_99|x.1\t"));
+        assert!(output.ends_with("ID=Rosalind_99|x.1_orf2\n"));
+    }
+}
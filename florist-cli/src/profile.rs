@@ -0,0 +1,195 @@
+//! Measures the time, and with the `count-alloc` feature the peak allocation,
+//! of each phase of solving a dataset.
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// The measurements of a single phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Phase {
+    pub elapsed: Duration,
+
+    /// The most memory allocated at once during the phase, above what was
+    /// allocated when it started. Only known with the `count-alloc` feature.
+    pub peak_alloc: Option<usize>,
+}
+
+/// The measurements of every phase of solving a dataset.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Profile {
+    /// Reading the dataset from its file or stdin.
+    pub read: Phase,
+
+    /// Making the input with `Problem::input_from`.
+    pub parse: Phase,
+
+    /// Running `Problem::solve`.
+    pub solve: Phase,
+
+    /// Formatting the output as text or JSON.
+    pub format: Phase,
+}
+
+impl Profile {
+    pub fn phases(&self) -> [(&'static str, &Phase); 4] {
+        [
+            ("read", &self.read),
+            ("parse", &self.parse),
+            ("solve", &self.solve),
+            ("format", &self.format),
+        ]
+    }
+
+    pub fn total(&self) -> Duration {
+        self.phases().iter().map(|(_, p)| p.elapsed).sum()
+    }
+
+    /// The largest peak allocation of any phase.
+    pub fn peak_alloc(&self) -> Option<usize> {
+        self.phases().iter().filter_map(|(_, p)| p.peak_alloc).max()
+    }
+}
+
+impl Display for Profile {
+    /// Renders a table of the phases, with a peak allocation column only if
+    /// allocations were counted.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show_peak = self.peak_alloc().is_some();
+        let mut row = |name: &str, elapsed: String, peak: Option<String>| {
+            write!(f, "{:<8} {:>12}", name, elapsed)?;
+            if show_peak {
+                write!(f, " {:>12}", peak.unwrap_or_else(|| "-".into()))?;
+            }
+            writeln!(f)
+        };
+
+        row("PHASE", "TIME".into(), Some("PEAK ALLOC".into()))?;
+        for (name, phase) in self.phases() {
+            row(
+                name,
+                format!("{:.2?}", phase.elapsed),
+                phase.peak_alloc.map(format_bytes),
+            )?;
+        }
+
+        row(
+            "total",
+            format!("{:.2?}", self.total()),
+            self.peak_alloc().map(format_bytes),
+        )
+    }
+}
+
+/// Runs the phase, measuring how long it takes and how much it allocates.
+pub(crate) fn measure<T, F: FnOnce() -> T>(phase: F) -> (T, Phase) {
+    let baseline = alloc::reset_peak();
+    let start = Instant::now();
+    let res = phase();
+    let elapsed = start.elapsed();
+
+    (
+        res,
+        Phase {
+            elapsed,
+            peak_alloc: alloc::peak_since(baseline),
+        },
+    )
+}
+
+/// Formats a number of bytes with a binary unit.
+pub(crate) fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+mod alloc {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    static CURRENT: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+    /// Wraps the system allocator, keeping track of the bytes currently
+    /// allocated and the most allocated at once.
+    struct CountingAllocator;
+
+    impl CountingAllocator {
+        fn grew(&self, bytes: usize) {
+            let current = CURRENT.fetch_add(bytes, Ordering::Relaxed) + bytes;
+            PEAK.fetch_max(current, Ordering::Relaxed);
+        }
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                self.grew(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                self.grew(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+                self.grew(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    /// Starts a new peak from what is currently allocated, returning that.
+    pub fn reset_peak() -> usize {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        current
+    }
+
+    pub fn peak_since(baseline: usize) -> Option<usize> {
+        Some(PEAK.load(Ordering::Relaxed).saturating_sub(baseline))
+    }
+}
+
+#[cfg(not(feature = "count-alloc"))]
+mod alloc {
+    pub fn reset_peak() -> usize {
+        0
+    }
+
+    pub fn peak_since(_baseline: usize) -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use florist_core::{DNASequence, FrameTranslation, GeneticCode, Strand};
+use florist_fasta::OrderedMultiFasta;
+
+use crate::cli::{read_input, write_output};
+
+/// Which reading frames are translated.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, ValueEnum)]
+pub(crate) enum Frames {
+    /// The first frame of the forward strand
+    #[default]
+    #[clap(name = "1")]
+    One,
+
+    /// Every frame of the forward strand
+    #[clap(name = "3")]
+    Three,
+
+    /// Every frame of both strands
+    #[clap(name = "6")]
+    Six,
+}
+
+impl Frames {
+    fn includes(&self, translation: &FrameTranslation) -> bool {
+        match self {
+            Self::One => translation.strand == Strand::Forward && translation.frame == 0,
+            Self::Three => translation.strand == Strand::Forward,
+            Self::Six => true,
+        }
+    }
+}
+
+/// Translate every sequence in a FASTA file, writing stops as `*`
+#[derive(Debug, Args)]
+pub struct Translate {
+    /// The path to the FASTA file, reads from stdin if omitted or `-`
+    input: Option<PathBuf>,
+
+    /// Write the proteins to this file instead of stdout
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// How many reading frames to translate
+    #[clap(long, value_enum, default_value_t)]
+    frames: Frames,
+
+    /// The NCBI genetic code to translate with, e.g. 2 for vertebrate
+    /// mitochondria or 11 for bacteria [default: 1]
+    #[clap(long, value_name = "ID")]
+    table: Option<GeneticCode>,
+}
+
+impl Translate {
+    pub(crate) fn run(&self) -> Result<()> {
+        let fasta: OrderedMultiFasta = read_input(self.input.as_deref())?
+            .parse()
+            .context("Failed to parse FASTA")?;
+        let code = self.table.unwrap_or_default();
+
+        let mut proteins = OrderedMultiFasta::default();
+        for (description, raw) in fasta.iter() {
+            let seq: DNASequence = raw
+                .parse()
+                .with_context(|| format!("Invalid sequence: {}", description))?;
+            let translations = seq
+                .six_frames(&code)
+                .with_context(|| format!("Failed to translate: {}", description))?;
+
+            // tag the ID, the first word of the description, with the frame
+            let (id, rest) = description
+                .split_once(char::is_whitespace)
+                .unwrap_or((description, ""));
+            for translation in translations.iter().filter(|t| self.frames.includes(t)) {
+                let tagged = format!(
+                    "{}_{}{} {}",
+                    id,
+                    translation.strand,
+                    translation.frame + 1,
+                    rest
+                );
+                proteins.push((tagged.trim_end().into(), translation.protein.to_string()));
+            }
+        }
+
+        write_output(self.output.as_deref(), proteins.to_string().trim_end())
+    }
+}
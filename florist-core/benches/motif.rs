@@ -0,0 +1,72 @@
+//! Compares searching for many motifs with the FM-index against scanning.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use florist_core::{DNASequence, FmIndex, Motif};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const LENGTHS: [usize; 3] = [1_000, 100_000, 1_000_000];
+const PRIMERS: usize = 100;
+const PRIMER_LEN: usize = 20;
+
+fn random_dna(rng: &mut StdRng, len: usize) -> DNASequence {
+    let raw = (0..len)
+        .map(|_| DNASequence::SYMBOLS.as_bytes()[rng.gen_range(0..4)] as char)
+        .collect();
+    DNASequence::new_unchecked(raw)
+}
+
+fn motif(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for len in LENGTHS {
+        let reference = random_dna(&mut rng, len);
+        // half the primers come from the reference, so there is something to
+        // locate
+        let primers: Vec<_> = (0..PRIMERS)
+            .map(|i| {
+                if i % 2 == 0 {
+                    let start = rng.gen_range(0..=(len - PRIMER_LEN));
+                    DNASequence::new_unchecked(reference[start..(start + PRIMER_LEN)].to_string())
+                } else {
+                    random_dna(&mut rng, PRIMER_LEN)
+                }
+            })
+            .collect();
+        let index = FmIndex::new(&reference);
+
+        let mut group = c.benchmark_group(format!("Motif search ({} bp)", len));
+        group.sample_size(10);
+
+        group.bench_function(BenchmarkId::new("locate", "scanning"), |bench| {
+            bench.iter(|| {
+                primers
+                    .iter()
+                    .map(|p| black_box(&reference).motif_lcoations(p))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function(BenchmarkId::new("locate", "FM-index"), |bench| {
+            bench.iter(|| {
+                primers
+                    .iter()
+                    .map(|p| black_box(&index).locate(p))
+                    .collect::<Vec<_>>()
+            })
+        });
+        group.bench_function(BenchmarkId::new("count", "FM-index"), |bench| {
+            bench.iter(|| {
+                primers
+                    .iter()
+                    .map(|p| black_box(&index).count(p))
+                    .sum::<usize>()
+            })
+        });
+        group.bench_function(BenchmarkId::new("build", "FM-index"), |bench| {
+            bench.iter(|| FmIndex::new(black_box(&reference)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, motif);
+criterion_main!(benches);
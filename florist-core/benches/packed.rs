@@ -0,0 +1,67 @@
+//! Compares the packed 2-bit sequences with the string ones.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use florist_core::{DNASequence, HammingDistance, PackedDNASequence};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const LENGTHS: [usize; 3] = [1_000, 100_000, 1_000_000];
+const K: usize = 21;
+
+fn random_dna(rng: &mut StdRng, len: usize) -> DNASequence {
+    let raw = (0..len)
+        .map(|_| DNASequence::SYMBOLS.as_bytes()[rng.gen_range(0..4)] as char)
+        .collect();
+    DNASequence::new_unchecked(raw)
+}
+
+fn packed(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for len in LENGTHS {
+        let a = random_dna(&mut rng, len);
+        let b = random_dna(&mut rng, len);
+        let packed_a = PackedDNASequence::from(&a);
+        let packed_b = PackedDNASequence::from(&b);
+
+        let mut group = c.benchmark_group(format!("Packed sequences ({} bp)", len));
+
+        group.bench_function(BenchmarkId::new("complement", "string"), |bench| {
+            bench.iter(|| black_box(&a).complement())
+        });
+        group.bench_function(BenchmarkId::new("complement", "packed"), |bench| {
+            bench.iter(|| black_box(&packed_a).complement())
+        });
+
+        group.bench_function(BenchmarkId::new("reverse complement", "string"), |bench| {
+            bench.iter(|| black_box(&a).reverse_complement())
+        });
+        group.bench_function(BenchmarkId::new("reverse complement", "packed"), |bench| {
+            bench.iter(|| black_box(&packed_a).reverse_complement())
+        });
+
+        group.bench_function(BenchmarkId::new("hamming distance", "string"), |bench| {
+            bench.iter(|| black_box(&a).hamming_distance(black_box(&b)))
+        });
+        group.bench_function(BenchmarkId::new("hamming distance", "packed"), |bench| {
+            bench.iter(|| black_box(&packed_a).hamming_distance(black_box(&packed_b)))
+        });
+
+        group.bench_function(BenchmarkId::new("k-mers", "string"), |bench| {
+            bench.iter(|| black_box(&a).as_bytes().windows(K).collect::<Vec<_>>())
+        });
+        group.bench_function(BenchmarkId::new("k-mers", "packed"), |bench| {
+            bench.iter(|| black_box(&packed_a).kmers(K).collect::<Vec<_>>())
+        });
+
+        group.bench_function(BenchmarkId::new("conversion", "pack"), |bench| {
+            bench.iter(|| PackedDNASequence::from(black_box(&a)))
+        });
+        group.bench_function(BenchmarkId::new("conversion", "unpack"), |bench| {
+            bench.iter(|| DNASequence::from(black_box(&packed_a)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, packed);
+criterion_main!(benches);
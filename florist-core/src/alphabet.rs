@@ -0,0 +1,197 @@
+//! The alphabets a [Sequence](crate::Sequence) can be written in.
+//!
+//! An alphabet is a zero-sized marker type supplying everything that differs
+//! between kinds of sequence: which symbols are valid, how they match, and,
+//! for nucleotides, complements, transitions and codons. Adding an alphabet is
+//! a matter of implementing these traits on a new marker.
+use std::{fmt::Debug, hash::Hash};
+
+use crate::{codon::Codon, AminoAcid, DNACodon, Error, RNACodon};
+
+/// The symbols a sequence may contain.
+pub trait Alphabet: Debug + Clone + Copy + Default + Eq + Hash + Send + Sync + 'static {
+    /// Every valid symbol, in the order profiles and generators use them.
+    const SYMBOLS: &'static str;
+
+    fn is_valid_char(ch: char) -> bool {
+        Self::SYMBOLS.contains(ch)
+    }
+
+    /// Whether the symbol in a motif matches the one in a sequence. Symbols
+    /// only match themselves, unless the alphabet has ambiguity codes.
+    fn symbol_matches(pattern: char, ch: char) -> bool {
+        pattern == ch
+    }
+}
+
+/// An alphabet of nucleotides, which pair with a complement.
+pub trait NucleicAlphabet: Alphabet {
+    /// The symbol pairing with the given one.
+    fn complement(ch: char) -> char;
+
+    /// Whether substituting one symbol for the other keeps a purine a purine,
+    /// or a pyrimidine a pyrimidine.
+    fn is_transition(from: char, to: char) -> bool;
+
+    /// Whether the symbol is guanine or cytosine.
+    fn is_gc(ch: char) -> bool {
+        matches!(ch, 'G' | 'C')
+    }
+}
+
+/// A nucleotide alphabet that is read in codons to make proteins.
+pub trait CodingAlphabet: NucleicAlphabet {
+    type Codon: Codon + TryInto<AminoAcid, Error = Error>;
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Dna;
+
+impl Alphabet for Dna {
+    const SYMBOLS: &'static str = "ACGT";
+}
+
+impl NucleicAlphabet for Dna {
+    fn complement(ch: char) -> char {
+        match ch {
+            'A' => 'T',
+            'T' => 'A',
+            'C' => 'G',
+            'G' => 'C',
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_transition(from: char, to: char) -> bool {
+        matches!(
+            (from, to),
+            ('A', 'G') | ('G', 'A') | ('C', 'T') | ('T', 'C')
+        )
+    }
+}
+
+impl CodingAlphabet for Dna {
+    type Codon = DNACodon;
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Rna;
+
+impl Alphabet for Rna {
+    const SYMBOLS: &'static str = "ACGU";
+}
+
+impl NucleicAlphabet for Rna {
+    fn complement(ch: char) -> char {
+        match ch {
+            'A' => 'U',
+            'U' => 'A',
+            'C' => 'G',
+            'G' => 'C',
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_transition(from: char, to: char) -> bool {
+        matches!(
+            (from, to),
+            ('A', 'G') | ('G', 'A') | ('C', 'U') | ('U', 'C')
+        )
+    }
+}
+
+impl CodingAlphabet for Rna {
+    type Codon = RNACodon;
+}
+
+/// The twenty standard amino acids.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Protein;
+
+impl Alphabet for Protein {
+    const SYMBOLS: &'static str = "ACDEFGHIKLMNPQRSTVWY";
+}
+
+/// The twenty standard amino acids and `*` for a stop, as translations of
+/// whole reading frames are written.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct TranslatedProtein;
+
+impl Alphabet for TranslatedProtein {
+    const SYMBOLS: &'static str = "ACDEFGHIKLMNPQRSTVWY*";
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{Consensus, HammingDistance, Motif, Sequence};
+
+    use super::*;
+
+    /// A reduced amino acid alphabet grouping residues by their side chains,
+    /// as used when clustering proteins.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+    struct SideChain;
+
+    impl Alphabet for SideChain {
+        // aliphatic, aromatic, charged, polar
+        const SYMBOLS: &'static str = "LRCP";
+    }
+
+    /// DNA that may carry 5-methylcytosine, written `M`.
+    #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+    struct Methylated;
+
+    impl Alphabet for Methylated {
+        const SYMBOLS: &'static str = "ACGTM";
+
+        /// Methylation does not change the base, so `C` matches either form.
+        fn symbol_matches(pattern: char, ch: char) -> bool {
+            pattern == ch || (pattern == 'C' && ch == 'M')
+        }
+    }
+
+    impl NucleicAlphabet for Methylated {
+        fn complement(ch: char) -> char {
+            match ch {
+                'M' => 'G',
+                _ => Dna::complement(ch),
+            }
+        }
+
+        fn is_transition(from: char, to: char) -> bool {
+            let unmethylate = |ch| if ch == 'M' { 'C' } else { ch };
+            Dna::is_transition(unmethylate(from), unmethylate(to))
+        }
+
+        fn is_gc(ch: char) -> bool {
+            matches!(ch, 'G' | 'C' | 'M')
+        }
+    }
+
+    #[test]
+    fn custom_alphabets() {
+        let seq = Sequence::<SideChain>::from_str("LRCPPL").unwrap();
+        let other = Sequence::<SideChain>::from_str("LRRPCL").unwrap();
+        assert_eq!(seq.hamming_distance(&other).ok(), Some(2));
+        assert!(matches!(
+            Sequence::<SideChain>::from_str("LRX"),
+            Err(Error::InvalidSequenceCharacter('X'))
+        ));
+
+        let third = Sequence::<SideChain>::from_str("PRCPPL").unwrap();
+        let consensus = Consensus::try_from_iter([seq, other, third].iter()).unwrap();
+        assert_eq!(consensus.sequence().as_str(), "LRCPPL");
+    }
+
+    #[test]
+    fn custom_nucleic_alphabets() {
+        let seq = Sequence::<Methylated>::from_str("AMGTCG").unwrap();
+        assert_eq!(seq.reverse_complement().as_str(), "CGACGT");
+        assert_eq!(seq.motif_lcoations(&"CG".parse().unwrap()), vec![1, 4]);
+
+        let gc = crate::GCContent::gc_content(&seq);
+        assert!((gc - 4.0 / 6.0).abs() < f64::EPSILON);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{DNACodon, Error, RNACodon};
+use crate::{DNACodon, Error, GeneticCode, RNACodon};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum AminoAcid {
@@ -26,15 +26,19 @@ pub enum AminoAcid {
     Ochre,
     Amber,
     Opal,
+    /// Any other stop, such as `AGA` in vertebrate mitochondria.
+    Stop,
 }
 
 impl AminoAcid {
+    /// Whether this begins a protein. Some genetic codes also start at other
+    /// codons, see [GeneticCode::is_start].
     pub fn is_start(&self) -> bool {
         matches!(self, Self::Methionine)
     }
 
     pub fn is_stop(&self) -> bool {
-        matches!(self, Self::Opal | Self::Ochre | Self::Amber)
+        matches!(self, Self::Opal | Self::Ochre | Self::Amber | Self::Stop)
     }
 
     pub fn abbreviation(&self) -> char {
@@ -63,6 +67,15 @@ impl AminoAcid {
         }
     }
 
+    /// The abbreviation, or `*` for a stop, as translations are written.
+    pub fn symbol(&self) -> char {
+        if self.is_stop() {
+            '*'
+        } else {
+            self.abbreviation()
+        }
+    }
+
     pub fn monoisotopic_mass(&self) -> f64 {
         match self {
             Self::Alanine => 71.03711,
@@ -141,6 +154,7 @@ impl TryFrom<char> for AminoAcid {
             'W' => AminoAcid::Tryptophan,
             'Y' => AminoAcid::Tyrosine,
             'V' => AminoAcid::Valine,
+            '*' => AminoAcid::Stop,
             _ => return Err(Error::UnknownAminoAcid(value)),
         })
     }
@@ -157,98 +171,9 @@ impl TryFrom<DNACodon> for AminoAcid {
 impl TryFrom<&DNACodon> for AminoAcid {
     type Error = Error;
 
+    /// Translates with the standard genetic code.
     fn try_from(value: &DNACodon) -> Result<Self, Self::Error> {
-        // yeah, this is a little nuts. My reasoning was that there would be
-        // fewer overall matches to perform if we went with one char at a time.
-        Ok(match value.0 {
-            'T' => match value.1 {
-                'T' => match value.2 {
-                    'T' | 'C' => Self::Phenylalanine,
-                    'A' | 'G' => Self::Leucine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Serine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'T' | 'C' => Self::Tyrosine,
-                    'A' => Self::Ochre,
-                    'G' => Self::Amber,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'T' | 'C' => Self::Cysteine,
-                    'A' => Self::Opal,
-                    'G' => Self::Tryptophan,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownDnaCodon(*value)),
-            },
-            'C' => match value.1 {
-                'T' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Leucine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Proline,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'T' | 'C' => Self::Histidine,
-                    'A' | 'G' => Self::Glutamine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Arginine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownDnaCodon(*value)),
-            },
-            'A' => match value.1 {
-                'T' => match value.2 {
-                    'T' | 'C' | 'A' => Self::Isoleucine,
-                    'G' => Self::Methionine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Threonine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'T' | 'C' => Self::Asparagine,
-                    'A' | 'G' => Self::Lysine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'T' | 'C' => Self::Serine,
-                    'A' | 'G' => Self::Arginine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownDnaCodon(*value)),
-            },
-            'G' => match value.1 {
-                'T' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Valine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Alanine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'T' | 'C' => Self::AsparticAcid,
-                    'A' | 'G' => Self::GlutamicAcid,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'T' | 'C' | 'A' | 'G' => Self::Glycine,
-                    _ => return Err(Error::UnknownDnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownDnaCodon(*value)),
-            },
-            _ => return Err(Error::UnknownDnaCodon(*value)),
-        })
+        GeneticCode::STANDARD.translate(value)
     }
 }
 
@@ -263,98 +188,9 @@ impl TryFrom<RNACodon> for AminoAcid {
 impl TryFrom<&RNACodon> for AminoAcid {
     type Error = Error;
 
+    /// Translates with the standard genetic code.
     fn try_from(value: &RNACodon) -> Result<Self, Self::Error> {
-        // yeah, this is a little nuts. My reasoning was that there would be
-        // fewer overall matches to perform if we went with one char at a time.
-        Ok(match value.0 {
-            'U' => match value.1 {
-                'U' => match value.2 {
-                    'U' | 'C' => Self::Phenylalanine,
-                    'A' | 'G' => Self::Leucine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Serine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'U' | 'C' => Self::Tyrosine,
-                    'A' => Self::Ochre,
-                    'G' => Self::Amber,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'U' | 'C' => Self::Cysteine,
-                    'A' => Self::Opal,
-                    'G' => Self::Tryptophan,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownRnaCodon(*value)),
-            },
-            'C' => match value.1 {
-                'U' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Leucine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Proline,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'U' | 'C' => Self::Histidine,
-                    'A' | 'G' => Self::Glutamine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Arginine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownRnaCodon(*value)),
-            },
-            'A' => match value.1 {
-                'U' => match value.2 {
-                    'U' | 'C' | 'A' => Self::Isoleucine,
-                    'G' => Self::Methionine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Threonine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'U' | 'C' => Self::Asparagine,
-                    'A' | 'G' => Self::Lysine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'U' | 'C' => Self::Serine,
-                    'A' | 'G' => Self::Arginine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownRnaCodon(*value)),
-            },
-            'G' => match value.1 {
-                'U' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Valine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'C' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Alanine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'A' => match value.2 {
-                    'U' | 'C' => Self::AsparticAcid,
-                    'A' | 'G' => Self::GlutamicAcid,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                'G' => match value.2 {
-                    'U' | 'C' | 'A' | 'G' => Self::Glycine,
-                    _ => return Err(Error::UnknownRnaCodon(*value)),
-                },
-                _ => return Err(Error::UnknownRnaCodon(*value)),
-            },
-            _ => return Err(Error::UnknownRnaCodon(*value)),
-        })
+        GeneticCode::STANDARD.translate(value)
     }
 }
 
@@ -396,6 +232,7 @@ mod tests {
         abbrev_test!(ochre, AminoAcid::Ochre, 'X');
         abbrev_test!(amber, AminoAcid::Amber, 'X');
         abbrev_test!(opal, AminoAcid::Opal, 'X');
+        abbrev_test!(stop, AminoAcid::Stop, 'X');
     }
 
     mod from_rna_codon {
@@ -0,0 +1,252 @@
+//! Motif searches that allow a few mismatches.
+//!
+//! [Motif::motif_lcoations] only finds exact matches, which misses primer
+//! binding sites with a mismatch or reads with sequencing errors. These
+//! searches find every place the motif matches with at most `k`
+//! substitutions, or with at most `k` substitutions, insertions and deletions
+//! between them.
+//!
+//! [Motif::motif_lcoations]: crate::Motif::motif_lcoations
+use crate::{Alphabet, SeqSlice, Sequence};
+
+/// How the differences between a motif and the sequence are counted.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Distance {
+    /// Substitutions only, so every match is as long as the motif.
+    #[default]
+    Hamming,
+
+    /// Substitutions, insertions and deletions, so matches may be longer or
+    /// shorter than the motif.
+    Edit,
+}
+
+/// A place a motif matches with some mismatches.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ApproximateMatch<'a, A> {
+    /// The part of the sequence the motif is aligned to.
+    pub location: SeqSlice<'a, A>,
+
+    /// How many substitutions, or edits, the motif differs from it by.
+    pub mismatches: u64,
+}
+
+impl<'a, A: Alphabet> ApproximateMatch<'a, A> {
+    /// Where the match starts in the sequence.
+    pub fn start(&self) -> usize {
+        self.location.start()
+    }
+
+    /// Where the match ends in the sequence, exclusive.
+    pub fn end(&self) -> usize {
+        self.location.end()
+    }
+
+    /// The matching part of the sequence, as its strand reads it.
+    pub fn substring(&self) -> Sequence<A> {
+        self.location.to_sequence()
+    }
+}
+
+impl<A: Alphabet> Sequence<A> {
+    /// Every place the motif matches with at most `max_mismatches`
+    /// differences, ordered by position. See [SeqSlice::approximate_motif_hits].
+    pub fn approximate_motif_hits(
+        &self,
+        motif: &Sequence<A>,
+        max_mismatches: u64,
+        distance: Distance,
+    ) -> Vec<ApproximateMatch<'_, A>> {
+        self.as_slice()
+            .approximate_motif_hits(motif, max_mismatches, distance)
+    }
+}
+
+impl<'a, A: Alphabet> SeqSlice<'a, A> {
+    /// Every place on this strand the motif matches with at most
+    /// `max_mismatches` differences, ordered by where they start as the
+    /// strand reads them. Ambiguity codes in the motif match as they do in
+    /// exact searches, so with no mismatches allowed this finds the same
+    /// places as [SeqSlice::motif_hits].
+    ///
+    /// With [Distance::Edit], each place the match could end is reported with
+    /// its best alignment, preferring the shortest. Of the matches starting
+    /// at the same place only the best is kept.
+    pub fn approximate_motif_hits(
+        &self,
+        motif: &Sequence<A>,
+        max_mismatches: u64,
+        distance: Distance,
+    ) -> Vec<ApproximateMatch<'a, A>> {
+        let hits = match distance {
+            Distance::Hamming => self.hamming_hits(motif, max_mismatches),
+            Distance::Edit => self.edit_hits(motif, max_mismatches),
+        };
+
+        hits.into_iter()
+            .filter_map(|(start, end, mismatches)| {
+                Some(ApproximateMatch {
+                    location: self.slice(start..end)?,
+                    mismatches,
+                })
+            })
+            .collect()
+    }
+
+    fn hamming_hits(&self, motif: &Sequence<A>, max_mismatches: u64) -> Vec<(usize, usize, u64)> {
+        if self.len() < motif.len() {
+            return Vec::new();
+        }
+
+        (0..=(self.len() - motif.len()))
+            .filter_map(|start| {
+                let end = start + motif.len();
+                let mismatches = self.slice(start..end)?.motif_mismatches(motif).ok()?;
+                (mismatches <= max_mismatches).then_some((start, end, mismatches))
+            })
+            .collect()
+    }
+
+    /// Aligns the whole motif to any part of the slice, keeping for every
+    /// row of the table the fewest edits so far and where that alignment
+    /// started in the slice. With as many edits as the motif is long, deleting
+    /// all of it would match the empty stretch before any position, so
+    /// alignments of at least one symbol are preferred and empty ones are
+    /// never reported.
+    fn edit_hits(&self, motif: &Sequence<A>, max_mismatches: u64) -> Vec<(usize, usize, u64)> {
+        let pattern: Vec<char> = motif.chars().collect();
+        let rows = pattern.len();
+
+        // deleting the first i symbols of the motif, before the slice starts
+        let mut column: Vec<(u64, usize)> = (0..=rows).map(|i| (i as u64, 0)).collect();
+        let mut hits = Vec::new();
+        let mut record = |end: usize, (edits, start): (u64, usize)| {
+            if edits <= max_mismatches && start < end {
+                hits.push((start, edits, end));
+            }
+        };
+        record(0, column[rows]);
+
+        for (j, ch) in self.chars().enumerate() {
+            // the motif may start anywhere, so the empty prefix is free
+            let mut next = vec![(0, j + 1)];
+            for i in 1..=rows {
+                let substitution = u64::from(!A::symbol_matches(pattern[i - 1], ch));
+                let best = [
+                    (column[i - 1].0 + substitution, column[i - 1].1),
+                    (next[i - 1].0 + 1, next[i - 1].1),
+                    (column[i].0 + 1, column[i].1),
+                ]
+                .into_iter()
+                // the fewest edits, then anything but the empty match, then
+                // the latest start for the shortest match
+                .min_by_key(|(edits, start)| (*edits, *start > j, std::cmp::Reverse(*start)))
+                .unwrap();
+                next.push(best);
+            }
+
+            column = next;
+            record(j + 1, column[rows]);
+        }
+
+        // keep the best match from each start, then the shortest
+        hits.sort_unstable();
+        hits.dedup_by_key(|(start, _, _)| *start);
+        hits.into_iter()
+            .map(|(start, edits, end)| (start, end, edits))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{AmbiguousDNASequence, DNASequence, Motif, Strand};
+
+    use super::*;
+
+    fn found<A: Alphabet>(hits: &[ApproximateMatch<'_, A>]) -> Vec<(usize, u64, String)> {
+        hits.iter()
+            .map(|hit| (hit.start(), hit.mismatches, hit.substring().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn allows_substitutions() {
+        let seq = DNASequence::from_str("GATATATGCATATACTT").unwrap();
+        let motif = DNASequence::from_str("ATAT").unwrap();
+
+        let exact = seq.approximate_motif_hits(&motif, 0, Distance::Hamming);
+        let starts: Vec<_> = exact.iter().map(|hit| hit.start()).collect();
+        assert_eq!(starts, seq.motif_lcoations(&motif));
+
+        let hits = seq.approximate_motif_hits(&motif, 1, Distance::Hamming);
+        assert_eq!(
+            found(&hits),
+            vec![
+                (1, 0, "ATAT".to_string()),
+                (3, 0, "ATAT".to_string()),
+                (9, 0, "ATAT".to_string()),
+                (11, 1, "ATAC".to_string()),
+            ]
+        );
+
+        // the motif never fits
+        let long = DNASequence::from_str("GATATATGCATATACTTA").unwrap();
+        assert!(seq
+            .approximate_motif_hits(&long, 5, Distance::Hamming)
+            .is_empty());
+    }
+
+    #[test]
+    fn allows_edits() {
+        let seq = DNASequence::from_str("CCGATTACACC").unwrap();
+
+        // an insertion in the sequence
+        let motif = DNASequence::from_str("GATACA").unwrap();
+        assert!(seq
+            .approximate_motif_hits(&motif, 0, Distance::Edit)
+            .is_empty());
+        let hits = seq.approximate_motif_hits(&motif, 1, Distance::Edit);
+        assert_eq!(found(&hits), vec![(2, 1, "GATTACA".to_string())]);
+
+        // a deletion from the sequence
+        let motif = DNASequence::from_str("GATTTACA").unwrap();
+        let hits = seq.approximate_motif_hits(&motif, 1, Distance::Edit);
+        assert_eq!(found(&hits), vec![(2, 1, "GATTACA".to_string())]);
+
+        // with no edits, the same as an exact search
+        let motif = DNASequence::from_str("AC").unwrap();
+        let hits = seq.approximate_motif_hits(&motif, 0, Distance::Edit);
+        let starts: Vec<_> = hits.iter().map(|hit| hit.start()).collect();
+        assert_eq!(starts, seq.motif_lcoations(&motif));
+        assert!(hits.iter().all(|hit| hit.mismatches == 0));
+
+        // enough edits to delete the whole motif, which must not match the
+        // empty stretches between symbols
+        let seq = DNASequence::from_str("GG").unwrap();
+        let hits = seq.approximate_motif_hits(&motif, 2, Distance::Edit);
+        assert_eq!(
+            found(&hits),
+            vec![(0, 2, "G".to_string()), (1, 2, "G".to_string())]
+        );
+        let hits = seq.approximate_motif_hits(&motif, 5, Distance::Edit);
+        assert!(hits.iter().all(|hit| hit.end() > hit.start()));
+    }
+
+    #[test]
+    fn matches_ambiguity_codes_on_either_strand() {
+        let seq = AmbiguousDNASequence::from_str("AAGCTTATGCCC").unwrap();
+        let motif = AmbiguousDNASequence::from_str("GGCNTA").unwrap();
+
+        // GGCATA reads along the reverse strand, with the N matching the A
+        let reverse = seq.as_slice().reverse_complement();
+        let hits = reverse.approximate_motif_hits(&motif, 1, Distance::Hamming);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].location.strand(), Strand::Reverse);
+        assert_eq!((hits[0].start(), hits[0].end()), (5, 11));
+        assert_eq!(hits[0].substring().as_str(), "GGCATA");
+        assert_eq!(hits[0].mismatches, 0);
+    }
+}
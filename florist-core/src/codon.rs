@@ -4,6 +4,12 @@ use crate::Error;
 
 pub trait Codon: TryFrom<(char, char, char)> {
     fn from_tuple_unchecked(v: (char, char, char)) -> Self;
+
+    /// The three bases, in the order they are read.
+    fn bases(&self) -> (char, char, char);
+
+    /// The error for a codon with no translation.
+    fn unknown(&self) -> Error;
 }
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
@@ -14,6 +20,14 @@ impl Codon for DNACodon {
     fn from_tuple_unchecked(value: (char, char, char)) -> Self {
         Self(value.0, value.1, value.2)
     }
+
+    fn bases(&self) -> (char, char, char) {
+        (self.0, self.1, self.2)
+    }
+
+    fn unknown(&self) -> Error {
+        Error::UnknownDnaCodon(*self)
+    }
 }
 
 impl TryFrom<(char, char, char)> for DNACodon {
@@ -64,6 +78,14 @@ impl Codon for RNACodon {
     fn from_tuple_unchecked(value: (char, char, char)) -> Self {
         Self(value.0, value.1, value.2)
     }
+
+    fn bases(&self) -> (char, char, char) {
+        (self.0, self.1, self.2)
+    }
+
+    fn unknown(&self) -> Error {
+        Error::UnknownRnaCodon(*self)
+    }
 }
 
 impl TryFrom<(char, char, char)> for RNACodon {
@@ -0,0 +1,151 @@
+//! Aligned sequences, which may contain gaps.
+//!
+//! The rows of an alignment are the same length, with [GAP] wherever a
+//! sequence has nothing aligned to the others. [Gapped] wraps an alphabet to
+//! accept gaps alongside its symbols, so the rows are still checked and can
+//! be built into a [Consensus] and its profile.
+use std::marker::PhantomData;
+
+use crate::{
+    Alphabet, AmbiguousDna, AmbiguousRna, Consensus, Dna, Error, Protein, Rna, Sequence,
+    TranslatedProtein,
+};
+
+/// The symbol written in aligned rows where a sequence has a gap.
+pub const GAP: char = '-';
+
+/// An alphabet with [GAP] as well as its own symbols.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Gapped<A>(PhantomData<A>);
+
+/// Implements [Alphabet] for a gapped alphabet, given every symbol it has
+/// with the gap last.
+macro_rules! gapped {
+    ($alphabet:ty, $symbols:literal) => {
+        impl Alphabet for Gapped<$alphabet> {
+            const SYMBOLS: &'static str = $symbols;
+
+            fn is_valid_char(ch: char) -> bool {
+                ch == GAP || <$alphabet>::is_valid_char(ch)
+            }
+
+            /// A gap only matches a gap.
+            fn symbol_matches(pattern: char, ch: char) -> bool {
+                if pattern == GAP || ch == GAP {
+                    pattern == ch
+                } else {
+                    <$alphabet>::symbol_matches(pattern, ch)
+                }
+            }
+        }
+    };
+}
+
+gapped!(Dna, "ACGT-");
+gapped!(Rna, "ACGU-");
+gapped!(Protein, "ACDEFGHIKLMNPQRSTVWY-");
+gapped!(TranslatedProtein, "ACDEFGHIKLMNPQRSTVWY*-");
+gapped!(AmbiguousDna, "ACGTRYSWKMBDHVN-");
+gapped!(AmbiguousRna, "ACGURYSWKMBDHVN-");
+
+impl<A: Alphabet> Sequence<Gapped<A>>
+where
+    Gapped<A>: Alphabet,
+{
+    /// The sequence with its gaps removed.
+    pub fn ungapped(&self) -> Sequence<A> {
+        Sequence::new_unchecked(self.chars().filter(|ch| *ch != GAP).collect())
+    }
+}
+
+impl<A: Alphabet> From<Sequence<A>> for Sequence<Gapped<A>>
+where
+    Gapped<A>: Alphabet,
+{
+    fn from(value: Sequence<A>) -> Self {
+        Sequence::new_unchecked(value.to_string())
+    }
+}
+
+/// How gaps count towards the consensus of a column.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum GapPolicy {
+    /// Gaps count like any other symbol, so a column that is mostly gaps has
+    /// a gap in the consensus.
+    #[default]
+    Count,
+
+    /// Gaps are left out of the vote, so only a column of nothing but gaps
+    /// has a gap in the consensus.
+    Ignore,
+}
+
+impl<'a, A: Alphabet> Consensus<Gapped<A>>
+where
+    Gapped<A>: Alphabet,
+{
+    /// The consensus of aligned rows, with gaps counted as the policy says.
+    /// The profile counts gaps either way.
+    pub fn from_alignment<I: Iterator<Item = &'a Sequence<Gapped<A>>>>(
+        iter: I,
+        policy: GapPolicy,
+    ) -> Result<Self, Error> {
+        match policy {
+            GapPolicy::Count => Self::tally(iter, None),
+            GapPolicy::Ignore => Self::tally(iter, Some(GAP)),
+        }
+    }
+
+    /// The consensus sequence without the columns where it has a gap.
+    pub fn ungapped_sequence(&self) -> Sequence<A> {
+        self.sequence().ungapped()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn accepts_gaps() {
+        let seq = Sequence::<Gapped<Dna>>::from_str("AC-GT-").unwrap();
+        assert_eq!(seq.ungapped().as_str(), "ACGT");
+        assert!(Sequence::<Gapped<Dna>>::from_str("AC-GU").is_err());
+        assert!(Sequence::<Gapped<Protein>>::from_str("MK-*").is_err());
+        assert!(Sequence::<Gapped<TranslatedProtein>>::from_str("MK-*").is_ok());
+        assert!(Sequence::<Dna>::from_str("AC-GT").is_err());
+    }
+
+    #[test]
+    fn builds_consensus_with_gaps() {
+        let rows: Vec<Sequence<Gapped<Dna>>> = ["AT-CG", "A--CG", "GT-C-", "AT-AG"]
+            .iter()
+            .map(|row| row.parse().unwrap())
+            .collect();
+
+        let counted = Consensus::from_alignment(rows.iter(), GapPolicy::Count).unwrap();
+        assert_eq!(counted.sequence().as_str(), "AT-CG");
+        assert_eq!(counted.ungapped_sequence().as_str(), "ATCG");
+        assert_eq!(counted.frequencies()[&GAP], vec![0, 1, 4, 0, 1]);
+
+        // the middle column is all gaps, so keeps its gap
+        let rows: Vec<Sequence<Gapped<Dna>>> = ["A--CG", "-T-C-", "-T-A-"]
+            .iter()
+            .map(|row| row.parse().unwrap())
+            .collect();
+        let counted = Consensus::from_alignment(rows.iter(), GapPolicy::Count).unwrap();
+        assert_eq!(counted.sequence().as_str(), "-T-C-");
+        let ignored = Consensus::from_alignment(rows.iter(), GapPolicy::Ignore).unwrap();
+        assert_eq!(ignored.sequence().as_str(), "AT-CG");
+        assert_eq!(ignored.frequencies(), counted.frequencies());
+
+        let uneven: Vec<Sequence<Gapped<Dna>>> =
+            vec!["AC-".parse().unwrap(), "AC".parse().unwrap()];
+        assert!(matches!(
+            Consensus::from_alignment(uneven.iter(), GapPolicy::Count),
+            Err(Error::NotEqualLength)
+        ));
+    }
+}
@@ -0,0 +1,332 @@
+//! The genetic codes codons are translated with, numbered as NCBI numbers
+//! them.
+//!
+//! Each table is written the way NCBI publishes it, as one amino acid and one
+//! start marker for each of the 64 codons, with the codons ordered by their
+//! bases in `TCAG` order (`TTT`, `TTC`, `TTA`, `TTG`, `TCT`, ...). A `*` is a
+//! stop, and an `M` in the starts can begin translation.
+//!
+//! Tables 27, 28 and 31, where a codon is either a stop or an amino acid
+//! depending on its context, are not supported.
+use std::{fmt::Display, str::FromStr};
+
+use crate::{codon::Codon, AminoAcid, Error};
+
+/// A table translating codons into amino acids, and saying which codons can
+/// start a protein.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct GeneticCode {
+    id: u8,
+    name: &'static str,
+    amino_acids: &'static [u8; 64],
+    starts: &'static [u8; 64],
+}
+
+/// Only `ATG`, which every table can start with.
+const ATG_ONLY: &[u8; 64] = b"-----------------------------------M----------------------------";
+
+macro_rules! table {
+    ($id:literal, $name:literal, $amino_acids:literal, $starts:literal) => {
+        GeneticCode {
+            id: $id,
+            name: $name,
+            amino_acids: $amino_acids,
+            starts: $starts,
+        }
+    };
+}
+
+impl GeneticCode {
+    /// Table 1, with its alternative starts `TTG` and `CTG`. Rosalind only
+    /// starts at `ATG`, see [GeneticCode::without_alternative_starts].
+    pub const STANDARD: Self = table!(
+        1,
+        "Standard",
+        b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"---M---------------M---------------M----------------------------"
+    );
+
+    /// Every supported table, in order of ID.
+    pub const TABLES: &'static [Self] = &[
+        Self::STANDARD,
+        table!(
+            2,
+            "Vertebrate Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+            b"--------------------------------MMMM---------------M------------"
+        ),
+        table!(
+            3,
+            "Yeast Mitochondrial",
+            b"FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"----------------------------------MM---------------M------------"
+        ),
+        table!(
+            4,
+            "Mold, Protozoan, and Coelenterate Mitochondrial and Mycoplasma/Spiroplasma",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"--MM---------------M------------MMMM---------------M------------"
+        ),
+        table!(
+            5,
+            "Invertebrate Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+            b"---M----------------------------MMMM---------------M------------"
+        ),
+        table!(
+            6,
+            "Ciliate, Dasycladacean and Hexamita Nuclear",
+            b"FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            9,
+            "Echinoderm and Flatworm Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+            b"-----------------------------------M---------------M------------"
+        ),
+        table!(
+            10,
+            "Euplotid Nuclear",
+            b"FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            11,
+            "Bacterial, Archaeal and Plant Plastid",
+            b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"---M---------------M------------MMMM---------------M------------"
+        ),
+        table!(
+            12,
+            "Alternative Yeast Nuclear",
+            b"FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-------------------M---------------M----------------------------"
+        ),
+        table!(
+            13,
+            "Ascidian Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+            b"---M------------------------------MM---------------M------------"
+        ),
+        table!(
+            14,
+            "Alternative Flatworm Mitochondrial",
+            b"FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            16,
+            "Chlorophycean Mitochondrial",
+            b"FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            21,
+            "Trematode Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+            b"-----------------------------------M---------------M------------"
+        ),
+        table!(
+            22,
+            "Scenedesmus obliquus Mitochondrial",
+            b"FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            23,
+            "Thraustochytrium Mitochondrial",
+            b"FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"--------------------------------M--M---------------M------------"
+        ),
+        table!(
+            24,
+            "Rhabdopleuridae Mitochondrial",
+            b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+            b"---M---------------M---------------M---------------M------------"
+        ),
+        table!(
+            25,
+            "Candidate Division SR1 and Gracilibacteria",
+            b"FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"---M-------------------------------M---------------M------------"
+        ),
+        table!(
+            26,
+            "Pachysolen tannophilus Nuclear",
+            b"FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-------------------M---------------M----------------------------"
+        ),
+        table!(
+            29,
+            "Mesodinium Nuclear",
+            b"FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            30,
+            "Peritrich Nuclear",
+            b"FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+            b"-----------------------------------M----------------------------"
+        ),
+        table!(
+            33,
+            "Cephalodiscidae Mitochondrial",
+            b"FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+            b"---M---------------M---------------M---------------M------------"
+        ),
+    ];
+
+    /// The table with the given NCBI ID.
+    pub fn from_id(id: u8) -> Result<Self, Error> {
+        Self::TABLES
+            .iter()
+            .find(|code| code.id == id)
+            .copied()
+            .ok_or_else(|| Error::UnknownGeneticCode(id.to_string()))
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The same table, but only starting translation at `ATG`.
+    pub fn without_alternative_starts(self) -> Self {
+        Self {
+            starts: ATG_ONLY,
+            ..self
+        }
+    }
+
+    /// The amino acid the codon codes for. Stops are named for the codons
+    /// that are stops in the standard code, and [AminoAcid::Stop] otherwise.
+    pub fn translate<C: Codon>(&self, codon: &C) -> Result<AminoAcid, Error> {
+        let idx = index(codon.bases()).ok_or_else(|| codon.unknown())?;
+
+        match self.amino_acids[idx] {
+            b'*' => Ok(match idx {
+                10 => AminoAcid::Ochre,
+                11 => AminoAcid::Amber,
+                14 => AminoAcid::Opal,
+                _ => AminoAcid::Stop,
+            }),
+            abbreviation => AminoAcid::try_from(abbreviation as char),
+        }
+    }
+
+    /// Whether translation can begin at the codon, in which case it codes for
+    /// methionine whatever it codes for elsewhere.
+    pub fn is_start<C: Codon>(&self, codon: &C) -> bool {
+        index(codon.bases()).is_some_and(|idx| self.starts[idx] == b'M')
+    }
+}
+
+impl Default for GeneticCode {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl Display for GeneticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.id, self.name)
+    }
+}
+
+/// Parses the NCBI ID of a table.
+impl FromStr for GeneticCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .parse()
+            .map_err(|_| Error::UnknownGeneticCode(s.into()))
+            .and_then(Self::from_id)
+    }
+}
+
+/// The position of the codon in a table, or `None` if it has a base other
+/// than `A`, `C`, `G`, `T` or `U`.
+fn index((first, second, third): (char, char, char)) -> Option<usize> {
+    let base = |ch| match ch {
+        'T' | 'U' => Some(0),
+        'C' => Some(1),
+        'A' => Some(2),
+        'G' => Some(3),
+        _ => None,
+    };
+
+    Some(base(first)? * 16 + base(second)? * 4 + base(third)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DNACodon, RNACodon};
+
+    use super::*;
+
+    fn dna(raw: &str) -> DNACodon {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn looks_up_tables() {
+        assert_eq!(GeneticCode::from_id(1).unwrap(), GeneticCode::STANDARD);
+        assert_eq!(GeneticCode::from_str("11").unwrap().id(), 11);
+        assert!(matches!(
+            GeneticCode::from_id(7),
+            Err(Error::UnknownGeneticCode(_))
+        ));
+        assert!(GeneticCode::from_str("mito").is_err());
+
+        // every table can start at ATG
+        for code in GeneticCode::TABLES {
+            assert!(code.is_start(&dna("ATG")), "table {}", code);
+        }
+    }
+
+    #[test]
+    fn translates_mitochondrial_codons() {
+        let mito = GeneticCode::from_id(2).unwrap();
+        assert_eq!(
+            mito.translate(&dna("TGA")).ok(),
+            Some(AminoAcid::Tryptophan)
+        );
+        assert_eq!(
+            mito.translate(&dna("ATA")).ok(),
+            Some(AminoAcid::Methionine)
+        );
+        assert_eq!(mito.translate(&dna("AGA")).ok(), Some(AminoAcid::Stop));
+        assert!(mito.translate(&dna("AGG")).unwrap().is_stop());
+
+        let rna: RNACodon = "UGA".parse().unwrap();
+        assert_eq!(mito.translate(&rna).ok(), Some(AminoAcid::Tryptophan));
+        assert_eq!(
+            GeneticCode::STANDARD.translate(&rna).ok(),
+            Some(AminoAcid::Opal)
+        );
+    }
+
+    #[test]
+    fn alternative_starts() {
+        let bacterial = GeneticCode::from_id(11).unwrap();
+        for start in ["ATG", "GTG", "TTG", "CTG", "ATT", "ATC", "ATA"] {
+            assert!(bacterial.is_start(&dna(start)), "{}", start);
+        }
+        assert!(!bacterial.is_start(&dna("AAA")));
+
+        // the amino acid is unchanged, translation starts with methionine
+        assert_eq!(
+            bacterial.translate(&dna("GTG")).ok(),
+            Some(AminoAcid::Valine)
+        );
+
+        let atg = bacterial.without_alternative_starts();
+        assert!(atg.is_start(&dna("ATG")));
+        assert!(!atg.is_start(&dna("GTG")));
+    }
+}
@@ -0,0 +1,285 @@
+//! A full-text index of a sequence, for searching it for many motifs.
+//!
+//! [FmIndex] is built once, in `O(n log² n)`, from the suffix array of the
+//! sequence and its Burrows-Wheeler transform. It then counts the matches of
+//! a motif in time proportional to the motif's length, and locates them in
+//! time proportional to the motif's length plus the number of matches,
+//! however long the sequence is. [Motif::motif_lcoations] compares the motif
+//! at every position instead, which is fine for one motif but slow for
+//! thousands.
+//!
+//! Matching follows [Alphabet::symbol_matches], so ambiguity codes in a motif
+//! match the same symbols they do when scanning.
+use std::{marker::PhantomData, ops::Range};
+
+use crate::{Alphabet, Motif, Sequence};
+
+/// How many symbols of the transform lie between stored occurrence counts.
+const OCC_SAMPLE: usize = 64;
+
+/// The rank of the sentinel ending the sequence, which sorts before every
+/// symbol.
+const SENTINEL: u8 = 0;
+
+/// An FM-index over a sequence in the alphabet `A`.
+#[derive(Debug, Clone)]
+pub struct FmIndex<A> {
+    /// The symbols of the sequence, in sorted order. Each is stored by its
+    /// position here plus one, leaving 0 for the sentinel.
+    symbols: Vec<u8>,
+
+    /// The start of every suffix of the sequence and sentinel, in sorted
+    /// order.
+    suffixes: Vec<usize>,
+
+    /// The symbol before each sorted suffix, as ranks.
+    bwt: Vec<u8>,
+
+    /// How many symbols in the sequence and sentinel sort before each rank.
+    less_than: Vec<usize>,
+
+    /// How many of each rank appear in the transform before every
+    /// `OCC_SAMPLE`th position.
+    occ: Vec<Vec<usize>>,
+
+    _alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet> FmIndex<A> {
+    pub fn new(seq: &Sequence<A>) -> Self {
+        let mut symbols: Vec<u8> = seq.bytes().collect();
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let mut ranks = [SENTINEL; 256];
+        for (i, symbol) in symbols.iter().enumerate() {
+            ranks[*symbol as usize] = i as u8 + 1;
+        }
+
+        let text: Vec<u8> = seq
+            .bytes()
+            .map(|b| ranks[b as usize])
+            .chain([SENTINEL])
+            .collect();
+        let suffixes = suffix_array(&text);
+        let bwt: Vec<u8> = suffixes
+            .iter()
+            .map(|&i| text[(i + text.len() - 1) % text.len()])
+            .collect();
+
+        let num_ranks = symbols.len() + 1;
+        let mut less_than = vec![0; num_ranks];
+        for &rank in &text {
+            for count in less_than.iter_mut().skip(rank as usize + 1) {
+                *count += 1;
+            }
+        }
+
+        let mut occ = Vec::with_capacity(bwt.len() / OCC_SAMPLE + 1);
+        let mut counts = vec![0; num_ranks];
+        for (i, &rank) in bwt.iter().enumerate() {
+            if i % OCC_SAMPLE == 0 {
+                occ.push(counts.clone());
+            }
+            counts[rank as usize] += 1;
+        }
+        occ.push(counts);
+
+        Self {
+            symbols,
+            suffixes,
+            bwt,
+            less_than,
+            occ,
+            _alphabet: PhantomData,
+        }
+    }
+
+    /// The length of the indexed sequence.
+    pub fn len(&self) -> usize {
+        self.suffixes.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How many times the motif occurs, overlaps included.
+    pub fn count(&self, motif: &Sequence<A>) -> usize {
+        self.matches(motif.as_bytes())
+            .into_iter()
+            .map(|range| range.len())
+            .sum()
+    }
+
+    /// Every position the motif occurs at, in order.
+    pub fn locate(&self, motif: &Sequence<A>) -> Vec<usize> {
+        let mut positions: Vec<usize> = self
+            .matches(motif.as_bytes())
+            .into_iter()
+            .flat_map(|range| self.suffixes[range].iter().copied())
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// The ranges of sorted suffixes starting with the motif. A motif with
+    /// ambiguity codes can match several different strings, each with its
+    /// own range.
+    fn matches(&self, motif: &[u8]) -> Vec<Range<usize>> {
+        // every suffix starts with the empty motif
+        let mut ranges: Vec<Range<usize>> = std::iter::once(0..self.suffixes.len()).collect();
+
+        // search backwards, narrowing each range to the suffixes that the
+        // next symbol of the motif extends
+        for &pattern in motif.iter().rev() {
+            let matching: Vec<u8> = self
+                .symbols
+                .iter()
+                .enumerate()
+                .filter(|(_, symbol)| A::symbol_matches(pattern as char, **symbol as char))
+                .map(|(i, _)| i as u8 + 1)
+                .collect();
+
+            ranges = ranges
+                .into_iter()
+                .flat_map(|range| {
+                    matching.iter().filter_map(move |&rank| {
+                        let base = self.less_than[rank as usize];
+                        let start = base + self.occ(rank, range.start);
+                        let end = base + self.occ(rank, range.end);
+                        (start < end).then_some(start..end)
+                    })
+                })
+                .collect();
+
+            if ranges.is_empty() {
+                break;
+            }
+        }
+
+        ranges
+    }
+
+    /// How many times the rank appears in the transform before the position.
+    fn occ(&self, rank: u8, pos: usize) -> usize {
+        let sample = pos / OCC_SAMPLE;
+        let sampled = self.occ[sample][rank as usize];
+        sampled
+            + self.bwt[(sample * OCC_SAMPLE)..pos]
+                .iter()
+                .filter(|r| **r == rank)
+                .count()
+    }
+}
+
+impl<A: Alphabet> Motif<Sequence<A>> for FmIndex<A> {
+    fn motif_lcoations(&self, motif: &Sequence<A>) -> Vec<usize> {
+        self.locate(motif)
+    }
+}
+
+impl<A: Alphabet> Sequence<A> {
+    /// Builds an index for searching this sequence for many motifs.
+    pub fn fm_index(&self) -> FmIndex<A> {
+        FmIndex::new(self)
+    }
+}
+
+/// Sorts the suffixes of the text, which ends in a unique smallest sentinel,
+/// by prefix doubling: suffixes are sorted by their first `k` symbols, then
+/// by their first `2k`, until every suffix has its own rank.
+fn suffix_array(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let mut suffixes: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = text.iter().map(|&r| r as usize).collect();
+    let mut next = vec![0; n];
+
+    let mut k = 1;
+    loop {
+        // suffixes running out before k more symbols sort first
+        let key = |i: usize| (rank[i], rank.get(i + k).map_or(0, |r| r + 1));
+        suffixes.sort_unstable_by_key(|&i| key(i));
+
+        next[suffixes[0]] = 0;
+        for w in 1..n {
+            let (prev, cur) = (suffixes[w - 1], suffixes[w]);
+            next[cur] = next[prev] + usize::from(key(prev) != key(cur));
+        }
+        std::mem::swap(&mut rank, &mut next);
+
+        if rank[suffixes[n - 1]] == n - 1 {
+            return suffixes;
+        }
+        k *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::{AmbiguousDNASequence, DNASequence, ProteinSequence};
+
+    use super::*;
+
+    #[test]
+    fn counts_and_locates() {
+        let seq = DNASequence::from_str("GATATATGCATATACTT").unwrap();
+        let index = seq.fm_index();
+        assert_eq!(index.len(), 17);
+
+        let motif = DNASequence::from_str("ATAT").unwrap();
+        assert_eq!(index.count(&motif), 3);
+        assert_eq!(index.locate(&motif), vec![1, 3, 9]);
+
+        let missing = DNASequence::from_str("GGG").unwrap();
+        assert_eq!(index.count(&missing), 0);
+        assert!(index.locate(&missing).is_empty());
+
+        let whole = DNASequence::from_str("GATATATGCATATACTTA").unwrap();
+        assert_eq!(index.count(&whole), 0);
+        assert_eq!(index.locate(&seq), vec![0]);
+    }
+
+    #[test]
+    fn agrees_with_scanning() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for len in [1, 2, 10, 63, 64, 65, 500] {
+            let raw: String = (0..len)
+                .map(|_| DNASequence::SYMBOLS.as_bytes()[rng.gen_range(0..4)] as char)
+                .collect();
+            let seq = DNASequence::new_unchecked(raw);
+            let index = seq.fm_index();
+
+            for _ in 0..20 {
+                let size = rng.gen_range(1..=4.min(len));
+                let start = rng.gen_range(0..=(len - size));
+                let motif = DNASequence::new_unchecked(seq[start..(start + size)].to_string());
+                assert_eq!(index.locate(&motif), seq.motif_lcoations(&motif));
+            }
+        }
+
+        // symbols missing from the sequence never match
+        let seq = ProteinSequence::from_str("MKVSMK").unwrap();
+        let index = seq.fm_index();
+        assert_eq!(
+            index.locate(&ProteinSequence::from_str("MK").unwrap()),
+            vec![0, 4]
+        );
+        assert_eq!(index.count(&ProteinSequence::from_str("W").unwrap()), 0);
+    }
+
+    #[test]
+    fn matches_ambiguity_codes() {
+        let seq = AmbiguousDNASequence::from_str("GATATATGCATATACTT").unwrap();
+        let index = seq.fm_index();
+
+        for motif in ["ATAT", "ANAT", "AYA", "NNNNN", "RRR"] {
+            let motif = AmbiguousDNASequence::from_str(motif).unwrap();
+            assert_eq!(index.locate(&motif), seq.motif_lcoations(&motif));
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Nucleotide sequences that may contain IUPAC ambiguity codes, as found in
+//! real-world FASTA files.
+//!
+//! [DNASequence] and [RNASequence] stay strict, accepting only unambiguous
+//! bases. The ambiguous sequences here accept every IUPAC code, and convert to
+//! the strict ones only when they contain no ambiguity.
+//!
+//! | Code | Bases   | Code | Bases   |
+//! |------|---------|------|---------|
+//! | `R`  | A, G    | `B`  | C, G, T |
+//! | `Y`  | C, T    | `D`  | A, G, T |
+//! | `S`  | C, G    | `H`  | A, C, T |
+//! | `W`  | A, T    | `V`  | A, C, G |
+//! | `K`  | G, T    | `N`  | any     |
+//! | `M`  | A, C    |      |         |
+use crate::{Alphabet, Consensus, DNASequence, Error, NucleicAlphabet, RNASequence, Sequence};
+
+/// A nucleotide alphabet with ambiguity codes.
+///
+/// Each code stands for a set of bases, held as a 4-bit mask with `A` as bit
+/// 0, `C` bit 1, `G` bit 2 and `T` (or `U`) bit 3.
+pub trait Iupac: NucleicAlphabet {
+    /// Every code, indexed by the mask of the bases it stands for. The empty
+    /// mask has no code.
+    const CODES: &'static str;
+
+    /// The mask of the bases the code stands for.
+    fn bases(code: char) -> u8 {
+        Self::CODES
+            .find(code)
+            .filter(|mask| *mask != 0)
+            .map(|mask| mask as u8)
+            .unwrap_or(0)
+    }
+
+    /// The code standing for exactly the given bases.
+    ///
+    /// # Panics
+    /// If the mask is empty or has bits beyond the four bases.
+    fn code(bases: u8) -> char {
+        assert!((1..16).contains(&bases), "Invalid base mask: {}", bases);
+        Self::CODES.as_bytes()[bases as usize] as char
+    }
+
+    /// The code for the complements of the bases the given code stands for.
+    fn complement_code(code: char) -> char {
+        let bases = Self::bases(code);
+        // complementing swaps A with T and C with G, reversing the mask
+        let complement = (bases & 0b0001) << 3
+            | (bases & 0b0010) << 1
+            | (bases & 0b0100) >> 1
+            | (bases & 0b1000) >> 3;
+
+        Self::code(complement)
+    }
+
+    /// Whether the codes can only stand for purines, or only pyrimidines.
+    fn same_class(from: char, to: char) -> bool {
+        const PURINES: u8 = 0b0101;
+        const PYRIMIDINES: u8 = 0b1010;
+
+        let bases = Self::bases(from) | Self::bases(to);
+        bases & PURINES == 0 || bases & PYRIMIDINES == 0
+    }
+
+    /// A code in a motif matches any code standing for some of its bases, so
+    /// `N` matches anything and `R` matches `A`, `G` and `R`.
+    fn covers(pattern: char, ch: char) -> bool {
+        Self::bases(ch) & !Self::bases(pattern) == 0
+    }
+}
+
+/// An alphabet with an ambiguity-aware counterpart.
+pub trait Ambiguity: Alphabet {
+    type Ambiguous: Iupac;
+}
+
+impl Ambiguity for crate::Dna {
+    type Ambiguous = AmbiguousDna;
+}
+
+impl Ambiguity for crate::Rna {
+    type Ambiguous = AmbiguousRna;
+}
+
+impl Ambiguity for AmbiguousDna {
+    type Ambiguous = Self;
+}
+
+impl Ambiguity for AmbiguousRna {
+    type Ambiguous = Self;
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct AmbiguousDna;
+
+impl Iupac for AmbiguousDna {
+    const CODES: &'static str = "-ACMGRSVTWYHKDBN";
+}
+
+impl Alphabet for AmbiguousDna {
+    const SYMBOLS: &'static str = "ACGTRYSWKMBDHVN";
+
+    fn is_valid_char(ch: char) -> bool {
+        Self::bases(ch) != 0
+    }
+
+    fn symbol_matches(pattern: char, ch: char) -> bool {
+        Self::covers(pattern, ch)
+    }
+}
+
+impl NucleicAlphabet for AmbiguousDna {
+    fn complement(ch: char) -> char {
+        Self::complement_code(ch)
+    }
+
+    fn is_transition(from: char, to: char) -> bool {
+        Self::same_class(from, to)
+    }
+
+    fn is_gc(ch: char) -> bool {
+        Self::covers('S', ch)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct AmbiguousRna;
+
+impl Iupac for AmbiguousRna {
+    const CODES: &'static str = "-ACMGRSVUWYHKDBN";
+}
+
+impl Alphabet for AmbiguousRna {
+    const SYMBOLS: &'static str = "ACGURYSWKMBDHVN";
+
+    fn is_valid_char(ch: char) -> bool {
+        Self::bases(ch) != 0
+    }
+
+    fn symbol_matches(pattern: char, ch: char) -> bool {
+        Self::covers(pattern, ch)
+    }
+}
+
+impl NucleicAlphabet for AmbiguousRna {
+    fn complement(ch: char) -> char {
+        Self::complement_code(ch)
+    }
+
+    fn is_transition(from: char, to: char) -> bool {
+        Self::same_class(from, to)
+    }
+
+    fn is_gc(ch: char) -> bool {
+        Self::covers('S', ch)
+    }
+}
+
+pub type AmbiguousDNASequence = Sequence<AmbiguousDna>;
+pub type AmbiguousRNASequence = Sequence<AmbiguousRna>;
+
+impl<A: Iupac> Sequence<A> {
+    /// Whether any position stands for more than one base.
+    pub fn is_ambiguous(&self) -> bool {
+        self.chars().any(|ch| A::bases(ch).count_ones() > 1)
+    }
+}
+
+// Conversions
+
+impl From<DNASequence> for AmbiguousDNASequence {
+    fn from(val: DNASequence) -> Self {
+        Self::new_unchecked(val.to_string())
+    }
+}
+
+impl From<RNASequence> for AmbiguousRNASequence {
+    fn from(val: RNASequence) -> Self {
+        Self::new_unchecked(val.to_string())
+    }
+}
+
+impl From<AmbiguousDNASequence> for AmbiguousRNASequence {
+    fn from(val: AmbiguousDNASequence) -> Self {
+        Self::new_unchecked(val.replace('T', "U"))
+    }
+}
+
+impl From<AmbiguousRNASequence> for AmbiguousDNASequence {
+    fn from(val: AmbiguousRNASequence) -> Self {
+        Self::new_unchecked(val.replace('U', "T"))
+    }
+}
+
+/// Fails on the first ambiguity code.
+impl TryFrom<AmbiguousDNASequence> for DNASequence {
+    type Error = Error;
+
+    fn try_from(value: AmbiguousDNASequence) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+/// Fails on the first ambiguity code.
+impl TryFrom<AmbiguousRNASequence> for RNASequence {
+    type Error = Error;
+
+    fn try_from(value: AmbiguousRNASequence) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+impl<A: Ambiguity> Consensus<A> {
+    /// The consensus as IUPAC codes, where each position stands for every
+    /// base seen in that column, so mixed columns become ambiguity codes.
+    pub fn iupac_sequence(&self) -> Sequence<A::Ambiguous> {
+        let len = self.sequence().len();
+        let mut masks = vec![0_u8; len];
+
+        for (symbol, counts) in self.frequencies() {
+            let bases = A::Ambiguous::bases(*symbol);
+            for (mask, count) in masks.iter_mut().zip(counts) {
+                if *count > 0 {
+                    *mask |= bases;
+                }
+            }
+        }
+
+        Sequence::new_unchecked(masks.into_iter().map(A::Ambiguous::code).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::Motif;
+
+    use super::*;
+
+    #[test]
+    fn parses_every_code() {
+        let seq = AmbiguousDNASequence::from_str("ACGTRYSWKMBDHVN").unwrap();
+        assert!(seq.is_ambiguous());
+        assert!(matches!(
+            AmbiguousDNASequence::from_str("ACGU"),
+            Err(Error::InvalidSequenceCharacter('U'))
+        ));
+        assert!(matches!(
+            AmbiguousDNASequence::from_str("AC-G"),
+            Err(Error::InvalidSequenceCharacter('-'))
+        ));
+        assert!(AmbiguousRNASequence::from_str("ACGURYN").is_ok());
+
+        // strict validation is unchanged
+        assert!(DNASequence::from_str("ACGTN").is_err());
+    }
+
+    #[test]
+    fn complements_every_code() {
+        let seq = AmbiguousDNASequence::from_str("ACGTRYSWKMBDHVN").unwrap();
+        assert_eq!(seq.complement().as_str(), "TGCAYRSWMKVHDBN");
+        assert_eq!(seq.reverse_complement().as_str(), "NBDHVKMWSRYACGT");
+        assert_eq!(seq.complement().complement(), seq);
+
+        // agrees with the strict complement on unambiguous bases
+        let strict = DNASequence::from_str("GATTACA").unwrap();
+        assert_eq!(
+            AmbiguousDNASequence::from(strict.clone()).reverse_complement(),
+            AmbiguousDNASequence::from(strict.reverse_complement())
+        );
+    }
+
+    #[test]
+    fn converts_to_strict() {
+        let seq = AmbiguousDNASequence::from_str("GATTACA").unwrap();
+        assert!(!seq.is_ambiguous());
+        assert_eq!(
+            DNASequence::try_from(seq).ok(),
+            DNASequence::from_str("GATTACA").ok()
+        );
+
+        let seq = AmbiguousDNASequence::from_str("GATNACA").unwrap();
+        assert!(matches!(
+            DNASequence::try_from(seq.clone()),
+            Err(Error::InvalidSequenceCharacter('N'))
+        ));
+        assert_eq!(AmbiguousRNASequence::from(seq).as_str(), "GAUNACA");
+    }
+
+    #[test]
+    fn motifs_match_ambiguity() {
+        let seq = AmbiguousDNASequence::from_str("GATATATGCATATACTT").unwrap();
+
+        let motif = AmbiguousDNASequence::from_str("ATAT").unwrap();
+        assert_eq!(seq.motif_lcoations(&motif), vec![1, 3, 9]);
+
+        let motif = AmbiguousDNASequence::from_str("ANAT").unwrap();
+        assert_eq!(seq.motif_lcoations(&motif), vec![1, 3, 9]);
+
+        let motif = AmbiguousDNASequence::from_str("AYA").unwrap();
+        assert_eq!(seq.motif_lcoations(&motif), vec![1, 3, 9, 11]);
+
+        let motif = AmbiguousDNASequence::from_str("NNNNN").unwrap();
+        assert_eq!(seq.motif_lcoations(&motif).len(), seq.len() - 4);
+
+        // an ambiguous base in the sequence only matches codes covering it
+        let seq = AmbiguousDNASequence::from_str("ARA").unwrap();
+        assert!(seq
+            .motif_lcoations(&AmbiguousDNASequence::from_str("AAA").unwrap())
+            .is_empty());
+        assert_eq!(
+            seq.motif_lcoations(&AmbiguousDNASequence::from_str("ARA").unwrap()),
+            vec![0]
+        );
+        assert_eq!(
+            seq.motif_lcoations(&AmbiguousDNASequence::from_str("ANA").unwrap()),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn consensus_has_codes_for_mixed_columns() {
+        let seqs: Vec<DNASequence> = ["ACGTA", "ACGAG"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let consensus = Consensus::try_from_iter(seqs.iter()).unwrap();
+        assert_eq!(consensus.iupac_sequence().as_str(), "ACGWR");
+
+        let seqs: Vec<AmbiguousRNASequence> = ["ACGUA", "ACCAN"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let consensus = Consensus::try_from_iter(seqs.iter()).unwrap();
+        assert_eq!(consensus.iupac_sequence().as_str(), "ACSWN");
+    }
+}
@@ -1,15 +1,39 @@
+pub mod alphabet;
 pub mod amino;
+pub mod approximate;
 pub mod codon;
+pub mod gapped;
+pub mod genetic_code;
+pub mod index;
+pub mod iupac;
+pub mod orf;
+pub mod packed;
 pub mod population;
+pub mod scoring;
 pub mod sequence;
+pub mod slice;
+pub mod translate;
 
+pub use alphabet::{
+    Alphabet, CodingAlphabet, Dna, NucleicAlphabet, Protein, Rna, TranslatedProtein,
+};
 pub use amino::AminoAcid;
+pub use approximate::{ApproximateMatch, Distance};
 pub use codon::{DNACodon, RNACodon};
+pub use gapped::{GapPolicy, Gapped, GAP};
+pub use genetic_code::GeneticCode;
+pub use index::FmIndex;
+pub use iupac::{AmbiguousDNASequence, AmbiguousDna, AmbiguousRNASequence, AmbiguousRna};
+pub use orf::{Orf, OrfFinder, StartPolicy};
+pub use packed::{Packed, PackedDNASequence, PackedRNASequence};
 pub use population::SingleGenePopulation;
+pub use scoring::{NucleotideScoring, ScoringMatrix};
 pub use sequence::{
     Consensus, DNASequence, GCContent, GeneticSequence, HammingDistance, Motif, ProteinSequence,
-    RNASequence, Sequence, Substitutable,
+    RNASequence, Sequence, Substitutable, TranslatedSequence,
 };
+pub use slice::{Frame, SeqSlice, Strand};
+pub use translate::{FrameTranslation, StartHandling, StopHandling, TranslationOptions};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -40,12 +64,33 @@ pub enum Error {
     #[error("Invalid character in RNA codon: {0}")]
     InvalidRNACodon(char),
 
+    #[error("Unknown genetic code: {0}")]
+    UnknownGeneticCode(String),
+
     #[error("Cannot reconstruct amino acid from: {0}")]
     UnknownAminoAcid(char),
 
     #[error("Wrong number of values. Expected {desired} but got {actual}")]
     WrongNumberOfValues { desired: usize, actual: usize },
 
-    #[error("No valid protein could be made from the given sequence of codons")]
-    NoValidProtein,
+    #[error("No start codon to begin translating from")]
+    NoStartCodon,
+
+    #[error("Translation must begin with a start codon, not {0}")]
+    MissingStartCodon(String),
+
+    #[error("Translation reached the end without a stop codon")]
+    MissingStopCodon,
+
+    #[error("Unexpected stop codon at position {0}")]
+    UnexpectedStopCodon(usize),
+
+    #[error("Coding sequence has a stop codon before its end, at position {0}")]
+    InternalStopCodon(usize),
+
+    #[error("Coding sequence length {0} is not a whole number of codons")]
+    IncompleteCodon(usize),
+
+    #[error("Invalid scoring matrix: {0}")]
+    InvalidScoringMatrix(String),
 }
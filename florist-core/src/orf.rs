@@ -0,0 +1,264 @@
+//! Open reading frames with their coordinates, found on both strands.
+//!
+//! An ORF runs from a start codon to the next stop codon in the same frame,
+//! including the stop. Coordinates are those of a [SeqSlice]: 0-based,
+//! half-open and on the forward strand of the sequence, whichever strand the
+//! ORF is on.
+use std::ops::Range;
+
+use serde::Serialize;
+
+use crate::{
+    codon::Codon, AminoAcid, CodingAlphabet, GeneticCode, ProteinSequence, SeqSlice, Sequence,
+    Strand,
+};
+
+/// Which starts ORFs are reported from, when several share a stop codon.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum StartPolicy {
+    /// Only the first start before each stop, giving the longest ORF.
+    #[default]
+    Longest,
+
+    /// Every start, including those nested inside a longer ORF.
+    Nested,
+}
+
+/// An open reading frame found by an [OrfFinder].
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Orf {
+    /// Where the ORF starts on the forward strand.
+    pub start: usize,
+
+    /// Where the ORF ends on the forward strand, exclusive.
+    pub end: usize,
+
+    pub strand: Strand,
+
+    /// How many bases from the start of its strand the ORF's frame begins,
+    /// which is 0, 1 or 2.
+    pub frame: usize,
+
+    /// The translation, without the stop.
+    pub protein: ProteinSequence,
+
+    /// Whether the ORF runs off the end of the sequence before a stop.
+    pub partial: bool,
+}
+
+impl Orf {
+    /// The number of nucleotides in the ORF, counting the stop codon.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// Finds the ORFs of a sequence.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OrfFinder {
+    /// The genetic code deciding which codons start and stop an ORF.
+    pub genetic_code: GeneticCode,
+
+    pub starts: StartPolicy,
+
+    /// The fewest nucleotides an ORF may have, counting the stop codon.
+    pub min_length: usize,
+
+    /// Whether to report ORFs that run off the end of the sequence.
+    pub partial: bool,
+}
+
+/// Finds the longest complete ORFs of any length, starting only at `ATG`.
+impl Default for OrfFinder {
+    fn default() -> Self {
+        Self {
+            genetic_code: GeneticCode::STANDARD.without_alternative_starts(),
+            starts: StartPolicy::default(),
+            min_length: 0,
+            partial: false,
+        }
+    }
+}
+
+impl OrfFinder {
+    /// Every ORF on either strand of the sequence, ordered by position.
+    pub fn find<A: CodingAlphabet>(&self, seq: &Sequence<A>) -> Vec<Orf> {
+        let forward = seq.as_slice();
+        let mut orfs = self.find_on(forward);
+        orfs.extend(self.find_on(forward.reverse_complement()));
+
+        orfs.sort_by_key(|orf| (orf.start, orf.end, orf.strand));
+        orfs
+    }
+
+    /// Every ORF on the strand the slice reads.
+    pub fn find_on<A: CodingAlphabet>(&self, strand: SeqSlice<'_, A>) -> Vec<Orf> {
+        let bases: Vec<char> = strand.chars().collect();
+        let codon =
+            |i: usize| A::Codon::from_tuple_unchecked((bases[i], bases[i + 1], bases[i + 2]));
+
+        let mut orfs = Vec::new();
+        for frame in 0..3 {
+            let mut starts = Vec::new();
+            let mut pos = frame;
+
+            while pos + 3 <= bases.len() {
+                let cur = codon(pos);
+                if self.translate(&cur).is_stop() {
+                    for start in starts.drain(..) {
+                        orfs.extend(self.orf(strand, codon, frame, start..(pos + 3), false));
+                    }
+                } else if self.genetic_code.is_start(&cur)
+                    && (starts.is_empty() || self.starts == StartPolicy::Nested)
+                {
+                    starts.push(pos);
+                }
+
+                pos += 3;
+            }
+
+            if self.partial {
+                for start in starts {
+                    orfs.extend(self.orf(strand, codon, frame, start..pos, true));
+                }
+            }
+        }
+
+        orfs
+    }
+
+    /// The ORF covering the range of the strand, unless it is too short.
+    fn orf<A: CodingAlphabet>(
+        &self,
+        strand: SeqSlice<'_, A>,
+        codon: impl Fn(usize) -> A::Codon,
+        frame: usize,
+        range: Range<usize>,
+        partial: bool,
+    ) -> Option<Orf> {
+        if range.len() < self.min_length {
+            return None;
+        }
+
+        // partial ORFs have no stop to leave off
+        let coding_end = if partial { range.end } else { range.end - 3 };
+        let protein = std::iter::once(AminoAcid::Methionine)
+            .chain(
+                (range.start + 3..coding_end)
+                    .step_by(3)
+                    .map(|pos| self.translate(&codon(pos))),
+            )
+            .map(|acid| acid.abbreviation())
+            .collect();
+
+        let location = strand.slice(range)?;
+        Some(Orf {
+            start: location.start(),
+            end: location.end(),
+            strand: location.strand(),
+            frame,
+            protein: ProteinSequence::new_unchecked(protein),
+            partial,
+        })
+    }
+
+    fn translate<C: Codon>(&self, codon: &C) -> AminoAcid {
+        // the sequence was validated when it was made
+        self.genetic_code
+            .translate(codon)
+            .expect("Sequence has invalid codons")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::DNASequence;
+
+    use super::*;
+
+    fn ranges(orfs: &[Orf]) -> Vec<(usize, usize, Strand)> {
+        orfs.iter().map(|o| (o.start, o.end, o.strand)).collect()
+    }
+
+    #[test]
+    fn finds_orfs_on_both_strands() {
+        // ATG AAA ATG CCC TAA forwards, and ATG AAA CCT TAG on the reverse
+        // strand
+        let seq = DNASequence::from_str("ATGAAAATGCCCTAAGGTTTCAT").unwrap();
+
+        let orfs = OrfFinder::default().find(&seq);
+        assert_eq!(
+            ranges(&orfs),
+            vec![(0, 15, Strand::Forward), (11, 23, Strand::Reverse)]
+        );
+        assert_eq!(orfs[0].protein.as_str(), "MKMP");
+        assert_eq!(orfs[0].len(), 15);
+        assert_eq!(orfs[0].frame, 0);
+        assert_eq!(orfs[1].protein.as_str(), "MKP");
+        assert!(!orfs[1].partial);
+
+        let nested = OrfFinder {
+            starts: StartPolicy::Nested,
+            ..Default::default()
+        };
+        let orfs = nested.find(&seq);
+        assert_eq!(orfs.len(), 3);
+        assert_eq!((orfs[1].start, orfs[1].end), (6, 15));
+        assert_eq!(orfs[1].protein.as_str(), "MP");
+    }
+
+    #[test]
+    fn filters_by_length() {
+        let seq = DNASequence::from_str("ATGAAAATGCCCTAAGGTTTCAT").unwrap();
+        let finder = OrfFinder {
+            min_length: 13,
+            ..Default::default()
+        };
+        assert_eq!(ranges(&finder.find(&seq)), vec![(0, 15, Strand::Forward)]);
+    }
+
+    #[test]
+    fn reports_partial_orfs() {
+        let seq = DNASequence::from_str("GGATGAAACCCG").unwrap();
+        assert!(OrfFinder::default().find(&seq).is_empty());
+
+        let finder = OrfFinder {
+            partial: true,
+            ..Default::default()
+        };
+        let orfs = finder.find(&seq);
+        assert_eq!(orfs.len(), 1);
+        assert_eq!((orfs[0].start, orfs[0].end, orfs[0].frame), (2, 11, 2));
+        assert_eq!(orfs[0].protein.as_str(), "MKP");
+        assert!(orfs[0].partial);
+    }
+
+    #[test]
+    fn uses_the_genetic_code() {
+        let seq = DNASequence::from_str("GTGAAATGAGG").unwrap();
+        assert!(OrfFinder::default().find(&seq).is_empty());
+
+        // GTG starts and TGA stops in bacteria, but TGA is tryptophan in
+        // vertebrate mitochondria, which stop at AGG instead
+        let finder = OrfFinder {
+            genetic_code: GeneticCode::from_id(11).unwrap(),
+            ..Default::default()
+        };
+        let orfs = finder.find(&seq);
+        assert_eq!(ranges(&orfs), vec![(0, 9, Strand::Forward)]);
+        assert_eq!(orfs[0].protein.as_str(), "MK");
+
+        let finder = OrfFinder {
+            genetic_code: GeneticCode::from_id(2).unwrap(),
+            ..Default::default()
+        };
+        let seq = DNASequence::from_str("ATGTGAAGG").unwrap();
+        assert_eq!(finder.find(&seq)[0].protein.as_str(), "MW");
+    }
+}
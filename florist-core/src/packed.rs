@@ -0,0 +1,385 @@
+//! Nucleotide sequences packed two bits per base, for sequences far larger
+//! than Rosalind's datasets.
+//!
+//! [Sequence] hands out its contents as a [String], which a packed sequence
+//! cannot do, so these live alongside the string forms instead. They convert
+//! losslessly to and from them, and implement the same [HammingDistance] and
+//! [GCContent] traits.
+//!
+//! Bases are encoded by their position in [Alphabet::SYMBOLS], so `A` is 0,
+//! `C` 1, `G` 2 and `T` (or `U`) 3. Complementing a base is then flipping both
+//! of its bits, which lets whole words be complemented at once.
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use crate::{Alphabet, Dna, Error, GCContent, HammingDistance, Rna, Sequence};
+
+/// The number of bases packed into each word.
+const BASES_PER_WORD: usize = 32;
+
+/// The low bit of every base in a word.
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+
+/// A nucleotide alphabet that can be packed, which needs exactly four symbols
+/// listed in complementary order.
+pub trait Packable: Alphabet {}
+
+impl Packable for Dna {}
+impl Packable for Rna {}
+
+/// A sequence in the alphabet `A` stored two bits per base.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Packed<A> {
+    /// Base `i` lives in bits `2 * (i % 32)` of word `i / 32`. Bits past the
+    /// end of the sequence are always zero.
+    words: Vec<u64>,
+    len: usize,
+    _alphabet: PhantomData<A>,
+}
+
+pub type PackedDNASequence = Packed<Dna>;
+pub type PackedRNASequence = Packed<Rna>;
+
+impl<A: Packable> Packed<A> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The 2-bit code of the base at the given position.
+    ///
+    /// # Panics
+    /// If the position is out of bounds.
+    pub fn code(&self, idx: usize) -> u8 {
+        assert!(idx < self.len, "index {} out of bounds", idx);
+        ((self.words[idx / BASES_PER_WORD] >> (2 * (idx % BASES_PER_WORD))) & 0b11) as u8
+    }
+
+    pub fn get(&self, idx: usize) -> Option<char> {
+        (idx < self.len).then(|| decode::<A>(self.code(idx)))
+    }
+
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        (0..self.len).map(|i| decode::<A>(self.code(i)))
+    }
+
+    pub fn complement(&self) -> Self {
+        let mut words: Vec<_> = self.words.iter().map(|w| !w).collect();
+        mask_tail(&mut words, self.len);
+        self.with_words(words)
+    }
+
+    pub fn reverse_complement(&self) -> Self {
+        // reversing every word, and the order of the words, reverses the
+        // sequence padded out to a whole number of words. The padding then
+        // sits at the front, so shift it off.
+        let mut words: Vec<_> = self
+            .words
+            .iter()
+            .rev()
+            .map(|w| !reverse_bases(*w))
+            .collect();
+        let padding = words.len() * BASES_PER_WORD - self.len;
+        shift_down(&mut words, padding);
+        mask_tail(&mut words, self.len);
+
+        self.with_words(words)
+    }
+
+    /// Every k-mer in order, each encoded with its first base in the most
+    /// significant position so that codes sort like the k-mers themselves.
+    ///
+    /// # Panics
+    /// If `k` is zero or more than 32.
+    pub fn kmers(&self, k: usize) -> Kmers<'_, A> {
+        assert!(
+            (1..=BASES_PER_WORD).contains(&k),
+            "k must be between 1 and {}",
+            BASES_PER_WORD
+        );
+
+        Kmers {
+            seq: self,
+            k,
+            pos: 0,
+            code: 0,
+        }
+    }
+
+    /// Turns a k-mer code from [Packed::kmers] back into a sequence.
+    pub fn decode_kmer(code: u64, k: usize) -> Sequence<A> {
+        let raw = (0..k)
+            .rev()
+            .map(|i| decode::<A>(((code >> (2 * i)) & 0b11) as u8))
+            .collect();
+        Sequence::new_unchecked(raw)
+    }
+
+    fn with_words(&self, words: Vec<u64>) -> Self {
+        Self {
+            words,
+            len: self.len,
+            _alphabet: PhantomData,
+        }
+    }
+
+    fn pack(s: &str) -> Result<Self, Error> {
+        let mut words = vec![0; s.len().div_ceil(BASES_PER_WORD)];
+        for (i, ch) in s.chars().enumerate() {
+            let code = encode::<A>(ch)?;
+            words[i / BASES_PER_WORD] |= (code as u64) << (2 * (i % BASES_PER_WORD));
+        }
+
+        Ok(Self {
+            words,
+            len: s.len(),
+            _alphabet: PhantomData,
+        })
+    }
+}
+
+/// Iterates over the k-mer codes of a packed sequence.
+#[derive(Debug, Clone)]
+pub struct Kmers<'a, A> {
+    seq: &'a Packed<A>,
+    k: usize,
+    pos: usize,
+    code: u64,
+}
+
+impl<'a, A: Packable> Iterator for Kmers<'a, A> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = if self.k == BASES_PER_WORD {
+            u64::MAX
+        } else {
+            (1 << (2 * self.k)) - 1
+        };
+
+        // the first k-mer needs k bases, every one after it just one more
+        while self.pos < self.seq.len {
+            self.code = ((self.code << 2) | self.seq.code(self.pos) as u64) & mask;
+            self.pos += 1;
+
+            if self.pos >= self.k {
+                return Some(self.code);
+            }
+        }
+
+        None
+    }
+}
+
+fn encode<A: Packable>(ch: char) -> Result<u8, Error> {
+    A::SYMBOLS
+        .chars()
+        .position(|sym| sym == ch)
+        .map(|code| code as u8)
+        .ok_or(Error::InvalidSequenceCharacter(ch))
+}
+
+fn decode<A: Packable>(code: u8) -> char {
+    A::SYMBOLS.as_bytes()[code as usize] as char
+}
+
+/// Reverses the order of the 2-bit bases within a word.
+fn reverse_bases(word: u64) -> u64 {
+    let word = ((word >> 2) & 0x3333_3333_3333_3333) | ((word & 0x3333_3333_3333_3333) << 2);
+    let word = ((word >> 4) & 0x0F0F_0F0F_0F0F_0F0F) | ((word & 0x0F0F_0F0F_0F0F_0F0F) << 4);
+    word.swap_bytes()
+}
+
+/// Moves every base `bases` positions towards the start, dropping the first
+/// ones. This is always less than a word.
+fn shift_down(words: &mut [u64], bases: usize) {
+    if bases == 0 {
+        return;
+    }
+
+    let bits = 2 * bases;
+    for i in 0..words.len() {
+        let next = words.get(i + 1).copied().unwrap_or(0);
+        words[i] = (words[i] >> bits) | (next << (64 - bits));
+    }
+}
+
+/// Zeroes the bits past the end of the sequence.
+fn mask_tail(words: &mut [u64], len: usize) {
+    let used = len % BASES_PER_WORD;
+    if let (Some(last), true) = (words.last_mut(), used != 0) {
+        *last &= (1 << (2 * used)) - 1;
+    }
+}
+
+impl<A: Packable> HammingDistance for Packed<A> {
+    type Error = Error;
+
+    fn hamming_distance(&self, other: &Self) -> Result<u64, Self::Error> {
+        if self.len != other.len {
+            return Err(Error::NotEqualLength);
+        }
+
+        Ok(self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| {
+                // a base differs if either of its bits does
+                let diff = a ^ b;
+                ((diff | (diff >> 1)) & LOW_BITS).count_ones() as u64
+            })
+            .sum())
+    }
+}
+
+impl<A: Packable> GCContent for Packed<A> {
+    fn gc_content(&self) -> f64 {
+        // C and G are the only bases whose two bits differ
+        let numer: u32 = self
+            .words
+            .iter()
+            .map(|w| ((w ^ (w >> 1)) & LOW_BITS).count_ones())
+            .sum();
+
+        numer as f64 / self.len as f64
+    }
+}
+
+impl<A: Packable> FromStr for Packed<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Error::EmptySequence);
+        }
+
+        Self::pack(s)
+    }
+}
+
+impl<A: Packable> Display for Packed<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.chars().collect::<String>().fmt(f)
+    }
+}
+
+impl<A: Packable> From<&Sequence<A>> for Packed<A> {
+    fn from(seq: &Sequence<A>) -> Self {
+        // the sequence was validated when it was made
+        Self::pack(seq).expect("Sequence has invalid characters")
+    }
+}
+
+impl<A: Packable> From<&Packed<A>> for Sequence<A> {
+    fn from(packed: &Packed<A>) -> Self {
+        Self::new_unchecked(packed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::{DNASequence, RNASequence};
+
+    use super::*;
+
+    /// Random DNA of every length across a few word boundaries.
+    fn sequences() -> impl Iterator<Item = DNASequence> {
+        let mut rng = StdRng::seed_from_u64(0);
+        (1..=100).map(move |len| {
+            let raw = (0..len)
+                .map(|_| DNASequence::SYMBOLS.as_bytes()[rng.gen_range(0..4)] as char)
+                .collect();
+            DNASequence::new_unchecked(raw)
+        })
+    }
+
+    #[test]
+    fn round_trips() {
+        for seq in sequences() {
+            let packed = PackedDNASequence::from(&seq);
+            assert_eq!(packed.len(), seq.len());
+            assert_eq!(DNASequence::from(&packed), seq);
+        }
+
+        let rna: RNASequence = "AUGCUUAG".parse().unwrap();
+        assert_eq!(RNASequence::from(&PackedRNASequence::from(&rna)), rna);
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert!(matches!(
+            PackedDNASequence::from_str("ACGU"),
+            Err(Error::InvalidSequenceCharacter('U'))
+        ));
+        assert!(matches!(
+            PackedDNASequence::from_str(""),
+            Err(Error::EmptySequence)
+        ));
+    }
+
+    #[test]
+    fn complements_match_strings() {
+        for seq in sequences() {
+            let packed = PackedDNASequence::from(&seq);
+            assert_eq!(packed.complement().to_string(), *seq.complement());
+            assert_eq!(
+                packed.reverse_complement().to_string(),
+                *seq.reverse_complement()
+            );
+            assert_eq!(packed.reverse_complement().reverse_complement(), packed);
+        }
+    }
+
+    #[test]
+    fn hamming_and_gc_match_strings() {
+        let seqs: Vec<_> = sequences().collect();
+        for pair in seqs.windows(2) {
+            let a = &pair[0];
+            let b = DNASequence::new_unchecked(pair[1][..a.len()].to_string());
+
+            let packed_a = PackedDNASequence::from(a);
+            let packed_b = PackedDNASequence::from(&b);
+            assert_eq!(
+                packed_a.hamming_distance(&packed_b).unwrap(),
+                a.hamming_distance(&b).unwrap()
+            );
+            assert!((packed_a.gc_content() - a.gc_content()).abs() < f64::EPSILON);
+        }
+
+        let short = PackedDNASequence::from_str("ACGT").unwrap();
+        let long = PackedDNASequence::from_str("ACGTA").unwrap();
+        assert!(matches!(
+            short.hamming_distance(&long),
+            Err(Error::NotEqualLength)
+        ));
+    }
+
+    #[test]
+    fn kmers_match_windows() {
+        for seq in sequences().step_by(7) {
+            let packed = PackedDNASequence::from(&seq);
+            for k in [1, 3, 31, 32] {
+                let kmers: Vec<_> = packed
+                    .kmers(k)
+                    .map(|code| PackedDNASequence::decode_kmer(code, k).to_string())
+                    .collect();
+                let windows: Vec<_> = seq
+                    .as_bytes()
+                    .windows(k)
+                    .map(|w| String::from_utf8_lossy(w).to_string())
+                    .collect();
+                assert_eq!(kmers, windows);
+            }
+        }
+
+        // codes sort like the k-mers they encode
+        let packed = PackedDNASequence::from_str("TAGC").unwrap();
+        let codes: Vec<_> = packed.kmers(2).collect();
+        assert!(codes[1] < codes[2] && codes[2] < codes[0]);
+    }
+}
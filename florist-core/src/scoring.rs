@@ -0,0 +1,302 @@
+//! Scores for substituting one symbol for another, as used to align
+//! sequences.
+use std::{marker::PhantomData, str::FromStr, sync::OnceLock};
+
+use crate::{AminoAcid, Error, NucleicAlphabet};
+
+/// A table of scores for every pair of amino acids, higher being more alike,
+/// in the format published by the NCBI.
+#[derive(Clone)]
+pub struct ScoringMatrix {
+    /// The symbols in the table, in the order of its header.
+    symbols: Vec<char>,
+    scores: Vec<i32>,
+    /// The score for any pair the table has no entry for.
+    lowest: i32,
+}
+
+impl ScoringMatrix {
+    /// Symbols are looked up by their ASCII code.
+    const SIZE: usize = 128;
+
+    /// BLOSUM45, for distantly related proteins.
+    pub fn blosum45() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/BLOSUM45").parse().unwrap())
+    }
+
+    /// BLOSUM50.
+    pub fn blosum50() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/BLOSUM50").parse().unwrap())
+    }
+
+    /// BLOSUM62, the usual choice for proteins.
+    pub fn blosum62() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/BLOSUM62").parse().unwrap())
+    }
+
+    /// BLOSUM80, for closely related proteins.
+    pub fn blosum80() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/BLOSUM80").parse().unwrap())
+    }
+
+    /// PAM30, for short and closely related proteins.
+    pub fn pam30() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/PAM30").parse().unwrap())
+    }
+
+    /// PAM70.
+    pub fn pam70() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/PAM70").parse().unwrap())
+    }
+
+    /// PAM250, for distantly related proteins.
+    pub fn pam250() -> &'static Self {
+        static MATRIX: OnceLock<ScoringMatrix> = OnceLock::new();
+        MATRIX.get_or_init(|| include_str!("../matrices/PAM250").parse().unwrap())
+    }
+
+    /// The score for aligning one amino acid with another. Every kind of stop
+    /// is scored as `*`.
+    pub fn score(&self, a: AminoAcid, b: AminoAcid) -> i32 {
+        self.score_symbols(a.symbol(), b.symbol())
+    }
+
+    /// The score for aligning one symbol with another, such as the ambiguity
+    /// codes `B`, `Z` and `X`. Pairs missing from the table, including any
+    /// symbol outside ASCII, score as the lowest score in it.
+    pub fn score_symbols(&self, a: char, b: char) -> i32 {
+        Self::index(a, b).map_or(self.lowest, |i| self.scores[i])
+    }
+
+    /// The symbols the table has scores for.
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    fn index(a: char, b: char) -> Option<usize> {
+        (a.is_ascii() && b.is_ascii()).then(|| a as usize * Self::SIZE + b as usize)
+    }
+}
+
+/// Reads a matrix in the NCBI format: `#` comments, a header row of symbols,
+/// then a row per symbol starting with it and giving a score for each symbol
+/// in the header. Every symbol in the header needs exactly one row.
+impl FromStr for ScoringMatrix {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: String| Error::InvalidScoringMatrix(reason);
+        let symbol = |field: &str| -> Result<char, Error> {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) if ch.is_ascii_graphic() => Ok(ch),
+                _ => Err(invalid(format!("Not a symbol: {}", field))),
+            }
+        };
+
+        let mut lines = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let symbols = lines
+            .next()
+            .ok_or_else(|| invalid("No header row".to_string()))?
+            .split_whitespace()
+            .map(symbol)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut scores = vec![None; Self::SIZE * Self::SIZE];
+        let mut rows = Vec::with_capacity(symbols.len());
+        for line in lines {
+            let mut fields = line.split_whitespace();
+            let row = symbol(fields.next().unwrap_or_default())?;
+            if rows.contains(&row) {
+                return Err(invalid(format!("Row {} appears more than once", row)));
+            }
+            rows.push(row);
+            let values: Vec<&str> = fields.collect();
+            if values.len() != symbols.len() {
+                return Err(invalid(format!(
+                    "Row {} has {} scores but the header has {} symbols",
+                    row,
+                    values.len(),
+                    symbols.len()
+                )));
+            }
+
+            for (col, value) in symbols.iter().zip(values) {
+                let score = value
+                    .parse()
+                    .map_err(|_| invalid(format!("Not a score: {}", value)))?;
+                // both symbols passed `symbol`, so are ASCII
+                scores[Self::index(row, *col).unwrap()] = Some(score);
+            }
+        }
+
+        if let Some(missing) = symbols.iter().find(|col| !rows.contains(col)) {
+            return Err(invalid(format!("No row for {}", missing)));
+        }
+
+        let lowest = scores
+            .iter()
+            .flatten()
+            .min()
+            .copied()
+            .ok_or_else(|| invalid("No scores".to_string()))?;
+
+        Ok(Self {
+            symbols,
+            scores: scores
+                .into_iter()
+                .map(|score| score.unwrap_or(lowest))
+                .collect(),
+            lowest,
+        })
+    }
+}
+
+impl std::fmt::Debug for ScoringMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScoringMatrix")
+            .field("symbols", &self.symbols)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Scores nucleotides, where a transition is usually more likely than a
+/// transversion, as [Substitutable](crate::Substitutable) counts them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NucleotideScoring<A> {
+    pub matches: i32,
+    pub transitions: i32,
+    pub transversions: i32,
+    alphabet: PhantomData<A>,
+}
+
+impl<A: NucleicAlphabet> NucleotideScoring<A> {
+    pub fn new(matches: i32, transitions: i32, transversions: i32) -> Self {
+        Self {
+            matches,
+            transitions,
+            transversions,
+            alphabet: PhantomData,
+        }
+    }
+
+    /// Scores every mismatch the same.
+    pub fn match_mismatch(matches: i32, mismatches: i32) -> Self {
+        Self::new(matches, mismatches, mismatches)
+    }
+
+    pub fn score(&self, a: char, b: char) -> i32 {
+        if a == b {
+            self.matches
+        } else if A::is_transition(a, b) {
+            self.transitions
+        } else {
+            self.transversions
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Dna;
+
+    use super::*;
+
+    #[test]
+    fn looks_up_matrices() {
+        use AminoAcid::*;
+
+        let blosum = ScoringMatrix::blosum62();
+        assert_eq!(blosum.score(Tryptophan, Tryptophan), 11);
+        assert_eq!(blosum.score(Alanine, Arginine), -1);
+        assert_eq!(blosum.score(Arginine, Alanine), -1);
+        assert_eq!(blosum.score(Opal, Amber), 1);
+        assert_eq!(blosum.score_symbols('B', 'D'), 4);
+        assert_eq!(blosum.symbols().len(), 24);
+
+        let pam = ScoringMatrix::pam250();
+        assert_eq!(pam.score(Tryptophan, Tryptophan), 17);
+        assert_eq!(pam.score(Phenylalanine, Tyrosine), 7);
+        assert_eq!(pam.score(Cysteine, Tryptophan), -8);
+
+        assert_eq!(ScoringMatrix::blosum45().score(Tryptophan, Tryptophan), 15);
+        assert_eq!(ScoringMatrix::blosum50().score(Cysteine, Cysteine), 13);
+        assert_eq!(
+            ScoringMatrix::blosum80().score(Tryptophan, AsparticAcid),
+            -6
+        );
+        assert_eq!(ScoringMatrix::pam30().score(Tryptophan, GlutamicAcid), -17);
+        assert_eq!(ScoringMatrix::pam70().score(Methionine, Methionine), 10);
+        for matrix in [
+            ScoringMatrix::blosum45(),
+            ScoringMatrix::blosum50(),
+            ScoringMatrix::blosum80(),
+            ScoringMatrix::pam30(),
+            ScoringMatrix::pam70(),
+        ] {
+            assert_eq!(matrix.symbols(), blosum.symbols());
+            for &a in matrix.symbols() {
+                for &b in matrix.symbols() {
+                    assert_eq!(matrix.score_symbols(a, b), matrix.score_symbols(b, a));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_custom_matrices() {
+        let matrix: ScoringMatrix = "# a toy matrix\n   A  C\nA  3 -1\nC -1  2\n"
+            .parse()
+            .unwrap();
+        assert_eq!(matrix.score_symbols('A', 'A'), 3);
+        assert_eq!(matrix.score_symbols('C', 'A'), -1);
+        // missing from the table
+        assert_eq!(matrix.score_symbols('A', 'W'), -1);
+        // 'Á' would alias 'A' if looked up modulo 128
+        assert_eq!(matrix.score_symbols('Á', 'Á'), -1);
+        assert_eq!(matrix.score_symbols('A', 'Ã'), -1);
+
+        assert!(matches!(
+            "A C\nA 1\n".parse::<ScoringMatrix>(),
+            Err(Error::InvalidScoringMatrix(_))
+        ));
+        assert!(matches!(
+            "A C\nA 1 x\n".parse::<ScoringMatrix>(),
+            Err(Error::InvalidScoringMatrix(_))
+        ));
+        assert!(matches!(
+            "# nothing\n".parse::<ScoringMatrix>(),
+            Err(Error::InvalidScoringMatrix(_))
+        ));
+        // duplicate row
+        assert!(matches!(
+            "A C\nA 3 -1\nC -1 2\nA 1 1\n".parse::<ScoringMatrix>(),
+            Err(Error::InvalidScoringMatrix(_))
+        ));
+        // no row for C
+        assert!(matches!(
+            "A C\nA 3 -1\n".parse::<ScoringMatrix>(),
+            Err(Error::InvalidScoringMatrix(_))
+        ));
+    }
+
+    #[test]
+    fn scores_transitions() {
+        let scoring = NucleotideScoring::<Dna>::new(2, -1, -3);
+        assert_eq!(scoring.score('A', 'A'), 2);
+        assert_eq!(scoring.score('C', 'T'), -1);
+        assert_eq!(scoring.score('A', 'T'), -3);
+
+        let flat = NucleotideScoring::<Dna>::match_mismatch(1, -1);
+        assert_eq!(flat.score('A', 'G'), flat.score('A', 'C'));
+    }
+}
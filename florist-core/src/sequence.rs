@@ -1,212 +1,127 @@
 //! Defines special string sequences and tools for working with them.
 use std::{
+    collections::BTreeMap,
     fmt::Display,
     hash::Hash,
     iter::Map,
     marker::PhantomData,
-    ops::Deref,
+    ops::{Deref, Range},
     str::{Chars, FromStr},
 };
 
 use itertools::{join, Itertools, Tuples};
 use rustc_hash::FxHashMap;
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{
+    alphabet::{Alphabet, CodingAlphabet, Dna, NucleicAlphabet, Protein, Rna, TranslatedProtein},
+    codon::Codon,
+    slice::{Frame, SeqSlice, SliceChars},
+    translate::{self, StartHandling, StopHandling, TranslationOptions},
+    AminoAcid, Error, GeneticCode,
+};
 
-use crate::{codon::Codon, AminoAcid, DNACodon, Error, RNACodon};
-
-pub trait Sequence:
-    FromStr
-    + TryFrom<String>
-    + Display
-    + std::fmt::Debug
-    + Clone
-    + Eq
-    + PartialEq
-    + Hash
-    + Deref<Target = String>
-{
-    const SYMBOLS: &'static str;
-
-    fn new_unchecked(val: String) -> Self;
-
-    fn is_valid_char(ch: char) -> bool;
-
-    fn symbols() -> Chars<'static> {
-        Self::SYMBOLS.chars()
-    }
-
-    fn frames(&self) -> Vec<Frame<Self>> {
-        (0..3)
-            .filter_map(|offset| Frame::new(self, offset))
-            .collect()
-    }
+/// A string of symbols from the alphabet `A`, validated on construction.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Sequence<A> {
+    raw: String,
+    _alphabet: PhantomData<A>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
-pub struct DNASequence(String);
+pub type DNASequence = Sequence<Dna>;
+pub type RNASequence = Sequence<Rna>;
+pub type ProteinSequence = Sequence<Protein>;
+pub type TranslatedSequence = Sequence<TranslatedProtein>;
 
-impl DNASequence {
-    pub fn complement(&self) -> Self {
-        Self(
-            self.chars()
-                .map(|ch| match ch {
-                    'A' => 'T',
-                    'T' => 'A',
-                    'C' => 'G',
-                    'G' => 'C',
-                    _ => unreachable!(),
-                })
-                .collect::<String>(),
-        )
-    }
+impl<A: Alphabet> Sequence<A> {
+    pub const SYMBOLS: &'static str = A::SYMBOLS;
 
-    pub fn reverse_complement(&self) -> Self {
-        Self(
-            self.chars()
-                .rev()
-                .map(|ch| match ch {
-                    'A' => 'T',
-                    'T' => 'A',
-                    'C' => 'G',
-                    'G' => 'C',
-                    _ => unreachable!(),
-                })
-                .collect::<String>(),
-        )
-    }
-
-    pub fn subsequence_unchecked(&self, start: usize, end: usize) -> Self {
-        Self(String::from_utf8_lossy(&self.as_bytes()[start..end]).into())
+    pub fn new_unchecked(val: String) -> Self {
+        Self {
+            raw: val,
+            _alphabet: PhantomData,
+        }
     }
 
-    pub fn open_frames(&self) -> Vec<Frame<Self>> {
-        let needle = "ATG";
-        self.0
-            .match_indices(needle)
-            .filter_map(|(i, _)| Frame::new(self, i))
-            .collect()
+    pub fn is_valid_char(ch: char) -> bool {
+        A::is_valid_char(ch)
     }
-}
-
-impl Sequence for DNASequence {
-    const SYMBOLS: &'static str = "ACGT";
 
-    fn new_unchecked(val: String) -> Self {
-        Self(val)
+    pub fn symbols() -> Chars<'static> {
+        A::SYMBOLS.chars()
     }
 
-    fn is_valid_char(ch: char) -> bool {
-        matches!(ch, 'A' | 'C' | 'G' | 'T')
+    /// The whole sequence as a borrowed slice of its forward strand.
+    pub fn as_slice(&self) -> SeqSlice<'_, A> {
+        // the full range is always in bounds
+        SeqSlice::new(self, 0..self.len()).unwrap()
     }
-}
-
-impl Deref for DNASequence {
-    type Target = String;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Borrows the range of the sequence, if it is in bounds.
+    pub fn slice(&self, range: Range<usize>) -> Option<SeqSlice<'_, A>> {
+        SeqSlice::new(self, range)
     }
-}
 
-impl Display for DNASequence {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+    pub fn frames(&self) -> Vec<Frame<'_, A>> {
+        self.as_slice().frames()
     }
-}
-
-impl FromStr for DNASequence {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(Error::EmptySequence);
-        }
 
-        for ch in s.chars() {
-            if !Self::is_valid_char(ch) {
-                return Err(Error::InvalidSequenceCharacter(ch));
-            }
-        }
-
-        Ok(Self(s.into()))
+    /// Copies the range into a new sequence. See [Sequence::slice] to borrow
+    /// it instead.
+    pub fn subsequence_unchecked(&self, start: usize, end: usize) -> Self {
+        Self::new_unchecked(self.raw[start..end].to_string())
     }
 }
 
-impl TryFrom<String> for DNASequence {
-    type Error = Error;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.is_empty() {
-            return Err(Error::EmptySequence);
-        }
-
-        for ch in value.chars() {
-            if !Self::is_valid_char(ch) {
-                return Err(Error::InvalidSequenceCharacter(ch));
-            }
-        }
-
-        Ok(Self(value))
+impl<A: NucleicAlphabet> Sequence<A> {
+    pub fn complement(&self) -> Self {
+        Self::new_unchecked(self.chars().map(A::complement).collect())
     }
-}
-
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
-pub struct RNASequence(String);
 
-impl RNASequence {
-    pub fn open_frames(&self) -> Vec<Frame<Self>> {
-        let needle = "AUG";
-        self.0
-            .match_indices(needle)
-            .filter_map(|(i, _)| Frame::new(self, i))
-            .collect()
+    pub fn reverse_complement(&self) -> Self {
+        Self::new_unchecked(self.chars().rev().map(A::complement).collect())
     }
 }
 
-impl Sequence for RNASequence {
-    const SYMBOLS: &'static str = "ACGU";
-
-    fn new_unchecked(val: String) -> Self {
-        Self(val)
+impl<A: CodingAlphabet> Sequence<A> {
+    pub fn open_frames(&self) -> Vec<Frame<'_, A>> {
+        self.as_slice().open_frames()
     }
 
-    fn is_valid_char(ch: char) -> bool {
-        matches!(ch, 'A' | 'C' | 'G' | 'U')
+    pub fn open_frames_with(&self, code: &GeneticCode) -> Vec<Frame<'_, A>> {
+        self.as_slice().open_frames_with(code)
     }
 }
 
-impl Deref for RNASequence {
+impl<A> Deref for Sequence<A> {
     type Target = String;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.raw
     }
 }
 
-impl Display for RNASequence {
+impl<A> Display for Sequence<A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
+        self.raw.fmt(f)
     }
 }
 
-impl FromStr for RNASequence {
+impl<A> Serialize for Sequence<A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<A: Alphabet> FromStr for Sequence<A> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(Error::EmptySequence);
-        }
-
-        for ch in s.chars() {
-            if !Self::is_valid_char(ch) {
-                return Err(Error::InvalidSequenceCharacter(ch));
-            }
-        }
-
-        Ok(Self(s.into()))
+        Self::try_from(s.to_string())
     }
 }
 
-impl TryFrom<String> for RNASequence {
+impl<A: Alphabet> TryFrom<String> for Sequence<A> {
     type Error = Error;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
@@ -215,12 +130,12 @@ impl TryFrom<String> for RNASequence {
         }
 
         for ch in value.chars() {
-            if !Self::is_valid_char(ch) {
+            if !A::is_valid_char(ch) {
                 return Err(Error::InvalidSequenceCharacter(ch));
             }
         }
 
-        Ok(Self(value))
+        Ok(Self::new_unchecked(value))
     }
 }
 
@@ -228,13 +143,13 @@ impl TryFrom<String> for RNASequence {
 
 impl From<DNASequence> for RNASequence {
     fn from(val: DNASequence) -> Self {
-        RNASequence(val.replace('T', "U"))
+        Self::new_unchecked(val.replace('T', "U"))
     }
 }
 
 impl From<RNASequence> for DNASequence {
     fn from(val: RNASequence) -> Self {
-        DNASequence(val.replace('U', "T"))
+        Self::new_unchecked(val.replace('U', "T"))
     }
 }
 
@@ -242,27 +157,15 @@ pub trait GCContent {
     fn gc_content(&self) -> f64;
 }
 
-impl GCContent for DNASequence {
-    fn gc_content(&self) -> f64 {
-        let numer = self.chars().filter(|ch| *ch == 'G' || *ch == 'C').count() as f64;
-        let denom = self.len() as f64;
-
-        numer / denom
-    }
-}
-
-impl GCContent for RNASequence {
+impl<A: NucleicAlphabet> GCContent for Sequence<A> {
     fn gc_content(&self) -> f64 {
-        let numer = self.chars().filter(|ch| *ch == 'G' || *ch == 'C').count() as f64;
+        let numer = self.chars().filter(|ch| A::is_gc(*ch)).count() as f64;
         let denom = self.len() as f64;
 
         numer / denom
     }
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
-pub struct ProteinSequence(String);
-
 impl ProteinSequence {
     pub fn monoisotopic_mass(&self) -> f64 {
         self.chars()
@@ -274,77 +177,18 @@ impl ProteinSequence {
     }
 }
 
-impl Sequence for ProteinSequence {
-    const SYMBOLS: &'static str = "ACDEFGHIKLMNPQRSTVWY";
-
-    fn new_unchecked(val: String) -> Self {
-        Self(val)
-    }
-
-    fn is_valid_char(ch: char) -> bool {
-        ch.is_ascii_uppercase()
-            && ch.is_ascii_alphabetic()
-            && !matches!(ch, 'B' | 'J' | 'O' | 'U' | 'X' | 'Z')
-    }
-}
-
-impl Deref for ProteinSequence {
-    type Target = String;
-
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl Display for ProteinSequence {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
-    }
-}
-
-impl FromStr for ProteinSequence {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(Error::EmptySequence);
-        }
-
-        for ch in s.chars() {
-            if !Self::is_valid_char(ch) {
-                return Err(Error::InvalidSequenceCharacter(ch));
-            }
-        }
-
-        Ok(Self(s.into()))
-    }
-}
-
-impl TryFrom<String> for ProteinSequence {
-    type Error = Error;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.is_empty() {
-            return Err(Error::EmptySequence);
-        }
-
-        for ch in value.chars() {
-            if !Self::is_valid_char(ch) {
-                return Err(Error::InvalidSequenceCharacter(ch));
-            }
-        }
-
-        Ok(Self(value))
-    }
-}
-
+/// Counts the positions where two equal length sequences differ.
+///
+/// Symbols are compared exactly, so ambiguity codes are not expanded: `N`
+/// against `A` is a mismatch. Use [SeqSlice::motif_mismatches] to let
+/// ambiguity codes match instead.
 pub trait HammingDistance<Other = Self> {
     type Error;
 
     fn hamming_distance(&self, other: &Other) -> Result<u64, Self::Error>;
 }
 
-impl<T: Sequence> HammingDistance for T {
+impl<A: Alphabet> HammingDistance for Sequence<A> {
     type Error = Error;
 
     fn hamming_distance(&self, other: &Self) -> Result<u64, Self::Error> {
@@ -353,9 +197,7 @@ impl<T: Sequence> HammingDistance for T {
         }
 
         let mut count = 0;
-        let mut pairs = self.chars().zip(other.chars());
-
-        while let Some((my, their)) = pairs.next() {
+        for (my, their) in self.chars().zip(other.chars()) {
             if my != their {
                 count += 1;
             }
@@ -369,7 +211,7 @@ pub trait Substitutable {
     fn transition_transversion_ratio(&self, other: &Self) -> Result<f64, Error>;
 }
 
-impl Substitutable for DNASequence {
+impl<A: NucleicAlphabet> Substitutable for Sequence<A> {
     fn transition_transversion_ratio(&self, other: &Self) -> Result<f64, Error> {
         if self.len() != other.len() {
             return Err(Error::NotEqualLength);
@@ -378,14 +220,9 @@ impl Substitutable for DNASequence {
         let mut transitions = 0_u64;
         let mut transversions = 0_u64;
 
-        let mut pairs = self.chars().zip(other.chars());
-
-        while let Some((my, their)) = pairs.next() {
+        for (my, their) in self.chars().zip(other.chars()) {
             if my != their {
-                if matches!(
-                    (my, their),
-                    ('A', 'G') | ('G', 'A') | ('C', 'T') | ('T', 'C')
-                ) {
+                if A::is_transition(my, their) {
                     transitions += 1;
                 } else {
                     transversions += 1;
@@ -401,207 +238,128 @@ impl Substitutable for DNASequence {
     }
 }
 
-impl Substitutable for RNASequence {
-    fn transition_transversion_ratio(&self, other: &Self) -> Result<f64, Error> {
-        if self.len() != other.len() {
-            return Err(Error::NotEqualLength);
-        }
-
-        let mut transitions = 0_u64;
-        let mut transversions = 0_u64;
-
-        let mut pairs = self.chars().zip(other.chars());
-
-        while let Some((my, their)) = pairs.next() {
-            if my != their {
-                if matches!(
-                    (my, their),
-                    ('A', 'G') | ('G', 'A') | ('C', 'U') | ('U', 'C')
-                ) {
-                    transitions += 1;
-                } else {
-                    transversions += 1;
-                }
-            }
-        }
-
-        if transversions == 0 {
-            return Ok(f64::NAN);
-        }
-
-        Ok(transitions as f64 / transversions as f64)
-    }
-}
+/// The iterator over the codons of a [GeneticSequence].
+pub type Codons<'a, C> =
+    Map<Tuples<SliceChars<'a>, (char, char, char)>, fn((char, char, char)) -> C>;
 
 pub trait GeneticSequence {
     type Codon: Codon + TryInto<AminoAcid, Error = Error>;
 
-    fn codons(
-        &self,
-    ) -> Map<Tuples<Chars<'_>, (char, char, char)>, fn((char, char, char)) -> Self::Codon>;
+    /// The nucleotides, in the order they are read.
+    fn nucleotides(&self) -> SliceChars<'_>;
 
-    fn to_protein(&self) -> Result<ProteinSequence, <Self::Codon as TryInto<AminoAcid>>::Error> {
-        let mut codons = self.codons();
-
-        let mut output = String::new();
-        while let Some(cur) = codons.next() {
-            let marker: AminoAcid = cur.try_into()?;
-            if marker.is_start() {
-                output.push(marker.abbreviation());
-
-                while let Some(next) = codons.next() {
-                    let acid: AminoAcid = next.try_into()?;
-                    if acid.is_stop() {
-                        return Ok(ProteinSequence(output));
-                    } else {
-                        output.push(acid.abbreviation());
-                    }
-                }
-            }
-        }
-
-        Err(Error::NoValidProtein)
+    /// Every whole codon, leaving off any bases after the last.
+    fn codons(&self) -> Codons<'_, Self::Codon> {
+        self.nucleotides()
+            .tuples()
+            .map(Self::Codon::from_tuple_unchecked)
     }
-}
-
-impl GeneticSequence for DNASequence {
-    type Codon = DNACodon;
 
-    fn codons(
-        &self,
-    ) -> Map<Tuples<Chars<'_>, (char, char, char)>, fn((char, char, char)) -> Self::Codon> {
-        self.chars().tuples().map(DNACodon::from_tuple_unchecked)
+    /// Translates as the options say, see [TranslationOptions].
+    fn translate(&self, options: &TranslationOptions) -> Result<TranslatedSequence, Error> {
+        translate::translate(self, options)
     }
-}
-
-impl GeneticSequence for RNASequence {
-    type Codon = RNACodon;
 
-    fn codons(
-        &self,
-    ) -> Map<Tuples<Chars<'_>, (char, char, char)>, fn((char, char, char)) -> Self::Codon> {
-        self.chars().tuples().map(RNACodon::from_tuple_unchecked)
+    /// Translates from the first `ATG` to the first stop with the standard
+    /// genetic code, as Rosalind does.
+    fn to_protein(&self) -> Result<ProteinSequence, <Self::Codon as TryInto<AminoAcid>>::Error> {
+        self.to_protein_with(&TranslationOptions::default().genetic_code)
     }
-}
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
-pub struct Frame<'a, T> {
-    sequence: &'a str,
-    _marker: PhantomData<T>,
-}
-
-impl<'a, T: Sequence> Frame<'a, T> {
-    fn new(sequence: &'a T, offset: usize) -> Option<Self> {
-        let slice = sequence.get(offset..)?;
-
-        if slice.is_empty() {
-            return None;
-        }
-
-        Some(Self {
-            sequence: slice,
-            _marker: PhantomData,
+    /// Translates from the first start codon of the genetic code to the first
+    /// stop. Alternative starts are read as methionine.
+    fn to_protein_with(&self, code: &GeneticCode) -> Result<ProteinSequence, Error> {
+        self.translate(&TranslationOptions {
+            genetic_code: *code,
+            ..Default::default()
+        })
+        // translation ends at the first stop, so there is no `*` to leave out
+        .map(|protein| ProteinSequence::new_unchecked(protein.to_string()))
+    }
+
+    /// Translates every whole codon from the first, whatever it is, writing
+    /// stops as `*`. This is the conceptual translation of the frame.
+    fn translate_frame(&self, code: &GeneticCode) -> Result<TranslatedSequence, Error> {
+        self.translate(&TranslationOptions {
+            genetic_code: *code,
+            start: StartHandling::Ignore,
+            stop: StopHandling::Emit,
+            strict_cds: false,
         })
     }
 }
 
-impl<'a, T: Sequence + GeneticSequence> GeneticSequence for Frame<'a, T> {
-    type Codon = <T as GeneticSequence>::Codon;
+impl<A: CodingAlphabet> GeneticSequence for Sequence<A> {
+    type Codon = A::Codon;
 
-    fn codons(
-        &self,
-    ) -> Map<Tuples<Chars<'_>, (char, char, char)>, fn((char, char, char)) -> Self::Codon> {
-        self.sequence
-            .chars()
-            .tuples()
-            .map(<T as GeneticSequence>::Codon::from_tuple_unchecked)
+    fn nucleotides(&self) -> SliceChars<'_> {
+        SliceChars::forward(self.chars())
     }
 }
 
-impl TryFrom<DNASequence> for ProteinSequence {
+impl<A: CodingAlphabet> TryFrom<Sequence<A>> for ProteinSequence {
     type Error = Error;
 
-    fn try_from(value: DNASequence) -> Result<Self, Self::Error> {
+    fn try_from(value: Sequence<A>) -> Result<Self, Self::Error> {
         Self::try_from(&value)
     }
 }
 
-impl TryFrom<&DNASequence> for ProteinSequence {
+impl<A: CodingAlphabet> TryFrom<&Sequence<A>> for ProteinSequence {
     type Error = Error;
 
-    fn try_from(value: &DNASequence) -> Result<Self, Self::Error> {
+    fn try_from(value: &Sequence<A>) -> Result<Self, Self::Error> {
         value.to_protein()
     }
 }
 
-impl TryFrom<RNASequence> for ProteinSequence {
-    type Error = Error;
-
-    fn try_from(value: RNASequence) -> Result<Self, Self::Error> {
-        Self::try_from(&value)
-    }
-}
-
-impl TryFrom<&RNASequence> for ProteinSequence {
-    type Error = Error;
-
-    fn try_from(value: &RNASequence) -> Result<Self, Self::Error> {
-        value.to_protein()
-    }
+pub trait Motif<Other = Self> {
+    fn motif_lcoations(&self, motif: &Other) -> Vec<usize>;
 }
 
-impl<'a, T: Sequence + GeneticSequence> TryFrom<Frame<'a, T>> for ProteinSequence {
-    type Error = Error;
-
-    fn try_from(value: Frame<'a, T>) -> Result<Self, Self::Error> {
-        Self::try_from(&value)
+impl<A: Alphabet> Motif for Sequence<A> {
+    fn motif_lcoations(&self, motif: &Self) -> Vec<usize> {
+        let haystack = self.as_bytes();
+        find_motif::<A>(haystack.len(), |i| haystack[i], motif.as_bytes())
     }
 }
 
-impl<'a, T: Sequence + GeneticSequence> TryFrom<&Frame<'a, T>> for ProteinSequence {
-    type Error = Error;
+/// Finds every position the needle matches in a haystack of the given
+/// length, reading the haystack through `at`.
+pub(crate) fn find_motif<A: Alphabet>(
+    len: usize,
+    at: impl Fn(usize) -> u8,
+    needle: &[u8],
+) -> Vec<usize> {
+    // we know we only allow a small subset of ascii chars, so the bytes in
+    // the sequence and motif are going to be individual chars.
+    let size = needle.len();
+    let mut indicies = Vec::new();
 
-    fn try_from(value: &Frame<'a, T>) -> Result<Self, Self::Error> {
-        value.to_protein()
+    if len < size {
+        return indicies;
     }
-}
-
-pub trait Motif {
-    fn motif_lcoations(&self, motif: &Self) -> Vec<usize>;
-}
-
-impl<T: Sequence> Motif for T {
-    fn motif_lcoations(&self, motif: &Self) -> Vec<usize> {
-        // we know we only allow a small subset of ascii chars, so the bytes in
-        // the sequence and motif are going to be individual chars.
-        let needle = motif.as_bytes();
-        let haystack = self.as_bytes();
-
-        let len = haystack.len();
-        let size = needle.len();
-        let mut indicies = Vec::new();
 
-        if len < size {
-            return indicies;
-        }
-
-        for i in 0..(len - size + 1) {
-            if &haystack[i..(i + size)] == needle {
-                indicies.push(i);
-            }
+    for i in 0..(len - size + 1) {
+        if needle
+            .iter()
+            .enumerate()
+            .all(|(j, pattern)| A::symbol_matches(*pattern as char, at(i + j) as char))
+        {
+            indicies.push(i);
         }
-
-        indicies
     }
+
+    indicies
 }
 
-pub struct Consensus<T: Sequence> {
-    sequence: T,
+pub struct Consensus<A: Alphabet> {
+    sequence: Sequence<A>,
     frequencies: FxHashMap<char, Vec<usize>>,
 }
 
-impl<'a, T: Sequence + 'a> Consensus<T> {
-    pub fn sequence(&self) -> &T {
+impl<'a, A: Alphabet> Consensus<A> {
+    pub fn sequence(&self) -> &Sequence<A> {
         &self.sequence
     }
 
@@ -609,7 +367,16 @@ impl<'a, T: Sequence + 'a> Consensus<T> {
         &self.frequencies
     }
 
-    pub fn try_from_iter<I: Iterator<Item = &'a T>>(iter: I) -> Result<Self, Error> {
+    pub fn try_from_iter<I: Iterator<Item = &'a Sequence<A>>>(iter: I) -> Result<Self, Error> {
+        Self::tally(iter, None)
+    }
+
+    /// Counts every column of the sequences, picking the most common symbol
+    /// other than `abstain` unless it is the only one in the column.
+    pub(crate) fn tally<I: Iterator<Item = &'a Sequence<A>>>(
+        iter: I,
+        abstain: Option<char>,
+    ) -> Result<Self, Error> {
         let seqs: Vec<_> = iter.map(|s| s.as_bytes()).collect();
 
         if seqs.is_empty() {
@@ -624,23 +391,28 @@ impl<'a, T: Sequence + 'a> Consensus<T> {
         let len = seqs[0].len();
 
         let mut frequencies: FxHashMap<char, Vec<usize>> =
-            FxHashMap::from_iter(T::symbols().map(|s| (s, vec![0; len])));
+            FxHashMap::from_iter(A::SYMBOLS.chars().map(|s| (s, vec![0; len])));
 
         let mut sequence = String::with_capacity(len);
 
         for i in 0..seqs[0].len() {
             let mut counts: FxHashMap<char, usize> = FxHashMap::default();
 
-            for s in 0..seqs.len() {
+            for seq in seqs.iter() {
                 // we're okay casting because we _know_ our sequences are ascii
                 counts
-                    .entry(seqs[s][i] as char)
+                    .entry(seq[i] as char)
                     .and_modify(|e| *e += 1)
                     .or_insert(1);
             }
 
             // we know this can't be empty, so unwrapping is fine
-            let (ch, _) = counts.iter().sorted().max_by(|a, b| a.1.cmp(&b.1)).unwrap();
+            let (ch, _) = counts
+                .iter()
+                .filter(|(ch, _)| Some(**ch) != abstain || counts.len() == 1)
+                .sorted()
+                .max_by(|a, b| a.1.cmp(b.1))
+                .unwrap();
             sequence.push(*ch);
 
             for (ch, count) in counts.iter() {
@@ -649,13 +421,26 @@ impl<'a, T: Sequence + 'a> Consensus<T> {
         }
 
         Ok(Self {
-            sequence: T::new_unchecked(sequence),
+            sequence: Sequence::new_unchecked(sequence),
             frequencies,
         })
     }
 }
 
-impl<T: Sequence> Display for Consensus<T> {
+/// Serializes as the consensus sequence plus a profile mapping each symbol to
+/// its count at every position.
+impl<A: Alphabet> Serialize for Consensus<A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let profile: BTreeMap<_, _> = self.frequencies.iter().collect();
+
+        let mut state = serializer.serialize_struct("Consensus", 2)?;
+        state.serialize_field("consensus", &self.sequence)?;
+        state.serialize_field("profile", &profile)?;
+        state.end()
+    }
+}
+
+impl<A: Alphabet> Display for Consensus<A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let freqs = self
             .frequencies
@@ -0,0 +1,497 @@
+//! Borrowed views into a [Sequence] that remember where they came from.
+//!
+//! A [SeqSlice] covers a range of its parent sequence on either strand,
+//! without copying it. Coordinates are always 0-based, half-open and on the
+//! forward strand of the parent, so a slice of the reverse strand covers the
+//! same range as the forward one, read backwards and complemented.
+use std::{fmt::Display, marker::PhantomData, ops::Range, str::Chars};
+
+use serde::Serialize;
+
+use crate::{
+    codon::Codon, sequence::find_motif, Alphabet, CodingAlphabet, Error, GCContent, GeneticCode,
+    GeneticSequence, HammingDistance, Motif, NucleicAlphabet, ProteinSequence, Sequence,
+};
+
+/// The strand of the parent sequence a slice reads.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strand {
+    #[default]
+    Forward,
+    Reverse,
+}
+
+impl Strand {
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+        }
+    }
+}
+
+/// Displays as `+` or `-`, as in most annotation formats.
+impl Display for Strand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Forward => '+'.fmt(f),
+            Self::Reverse => '-'.fmt(f),
+        }
+    }
+}
+
+/// A borrowed range of a sequence, on either strand.
+#[derive(Clone, Copy)]
+pub struct SeqSlice<'a, A> {
+    parent: &'a str,
+    start: usize,
+    end: usize,
+    strand: Strand,
+
+    /// Set for the reverse strand, which only nucleotide alphabets have.
+    complement: Option<fn(char) -> char>,
+    _alphabet: PhantomData<A>,
+}
+
+/// A frame is a slice that runs to the end of its strand.
+pub type Frame<'a, A> = SeqSlice<'a, A>;
+
+impl<'a, A: Alphabet> SeqSlice<'a, A> {
+    /// The slice of the parent's forward strand covering the range, if it is
+    /// in bounds.
+    pub(crate) fn new(parent: &'a Sequence<A>, range: Range<usize>) -> Option<Self> {
+        parent.get(range.clone())?;
+
+        Some(Self {
+            parent: parent.as_str(),
+            start: range.start,
+            end: range.end,
+            strand: Strand::Forward,
+            complement: None,
+            _alphabet: PhantomData,
+        })
+    }
+
+    /// Where the slice starts in the parent.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Where the slice ends in the parent, exclusive.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The symbols of the slice, in the order its strand reads them.
+    pub fn chars(&self) -> SliceChars<'a> {
+        SliceChars {
+            chars: self.parent[self.start..self.end].chars(),
+            complement: self.complement,
+        }
+    }
+
+    /// The slice as it is stored in the parent, which is only possible on the
+    /// forward strand.
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self.strand {
+            Strand::Forward => Some(&self.parent[self.start..self.end]),
+            Strand::Reverse => None,
+        }
+    }
+
+    /// Copies the slice into an owned sequence.
+    pub fn to_sequence(&self) -> Sequence<A> {
+        Sequence::new_unchecked(self.chars().collect())
+    }
+
+    /// The part of this slice covering the range, which is relative to the
+    /// start of the slice as its strand reads it.
+    pub fn slice(&self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+
+        let (start, end) = match self.strand {
+            Strand::Forward => (self.start + range.start, self.start + range.end),
+            Strand::Reverse => (self.end - range.end, self.end - range.start),
+        };
+
+        Some(Self {
+            start,
+            end,
+            ..*self
+        })
+    }
+
+    /// The slices starting at each of the first three positions and running
+    /// to the end.
+    pub fn frames(&self) -> Vec<Frame<'a, A>> {
+        (0..3)
+            .filter_map(|offset| self.slice(offset..self.len()))
+            .filter(|frame| !frame.is_empty())
+            .collect()
+    }
+
+    /// Every place the motif matches, as slices of the parent on this strand.
+    pub fn motif_hits(&self, motif: &Sequence<A>) -> Vec<Self> {
+        self.motif_lcoations(motif)
+            .into_iter()
+            .filter_map(|i| self.slice(i..(i + motif.len())))
+            .collect()
+    }
+
+    /// Counts the positions where the motif does not match the slice, as its
+    /// strand reads it, with ambiguity codes in the motif matching as they do
+    /// in [Motif::motif_lcoations]. [HammingDistance] compares symbols
+    /// exactly instead.
+    pub fn motif_mismatches(&self, motif: &Sequence<A>) -> Result<u64, Error> {
+        if self.len() != motif.len() {
+            return Err(Error::NotEqualLength);
+        }
+
+        Ok(self
+            .chars()
+            .zip(motif.chars())
+            .filter(|(my, pattern)| !A::symbol_matches(*pattern, *my))
+            .count() as u64)
+    }
+
+    /// The symbol at the position, as the strand reads it.
+    fn byte_at(&self, idx: usize) -> u8 {
+        match self.complement {
+            None => self.parent.as_bytes()[self.start + idx],
+            Some(complement) => {
+                complement(self.parent.as_bytes()[self.end - 1 - idx] as char) as u8
+            }
+        }
+    }
+}
+
+impl<'a, A: NucleicAlphabet> SeqSlice<'a, A> {
+    /// The same range read from the other strand.
+    pub fn reverse_complement(&self) -> Self {
+        let strand = self.strand.opposite();
+        let complement = match strand {
+            Strand::Forward => None,
+            Strand::Reverse => Some(A::complement as fn(char) -> char),
+        };
+
+        Self {
+            strand,
+            complement,
+            ..*self
+        }
+    }
+}
+
+impl<'a, A: CodingAlphabet> SeqSlice<'a, A> {
+    /// The slices from each `ATG` to the end of the strand.
+    pub fn open_frames(&self) -> Vec<Frame<'a, A>> {
+        self.open_frames_with(&GeneticCode::STANDARD.without_alternative_starts())
+    }
+
+    /// The slices from each start codon of the genetic code to the end of the
+    /// strand.
+    pub fn open_frames_with(&self, code: &GeneticCode) -> Vec<Frame<'a, A>> {
+        (0..self.len().saturating_sub(2))
+            .filter(|&i| {
+                let at = |j| self.byte_at(j) as char;
+                code.is_start(&A::Codon::from_tuple_unchecked((
+                    at(i),
+                    at(i + 1),
+                    at(i + 2),
+                )))
+            })
+            .filter_map(|i| self.slice(i..self.len()))
+            .collect()
+    }
+}
+
+impl<A> std::fmt::Debug for SeqSlice<'_, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SeqSlice")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("strand", &self.strand)
+            .finish()
+    }
+}
+
+/// Slices are equal if they cover the same range of equal parents, on the
+/// same strand.
+impl<A> PartialEq for SeqSlice<'_, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.end == other.end
+            && self.strand == other.strand
+            && self.parent == other.parent
+    }
+}
+
+impl<A> Eq for SeqSlice<'_, A> {}
+
+impl<A: Alphabet> Display for SeqSlice<'_, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.as_str() {
+            Some(raw) => raw.fmt(f),
+            None => self.chars().collect::<String>().fmt(f),
+        }
+    }
+}
+
+impl<'a, A: Alphabet> Motif<Sequence<A>> for SeqSlice<'a, A> {
+    /// The positions are relative to the start of the slice, as its strand
+    /// reads it. Use [SeqSlice::motif_hits] for coordinates in the parent.
+    fn motif_lcoations(&self, motif: &Sequence<A>) -> Vec<usize> {
+        find_motif::<A>(self.len(), |i| self.byte_at(i), motif.as_bytes())
+    }
+}
+
+/// Counts the positions where the symbols differ, as the strand reads them,
+/// the same way as for [Sequence]. Ambiguity codes are compared exactly, so
+/// `N` does not match `A`. See [SeqSlice::motif_mismatches] to let them
+/// match.
+impl<A: Alphabet> HammingDistance<Sequence<A>> for SeqSlice<'_, A> {
+    type Error = Error;
+
+    fn hamming_distance(&self, other: &Sequence<A>) -> Result<u64, Self::Error> {
+        if self.len() != other.len() {
+            return Err(Error::NotEqualLength);
+        }
+
+        Ok(self
+            .chars()
+            .zip(other.chars())
+            .filter(|(my, their)| my != their)
+            .count() as u64)
+    }
+}
+
+impl<A: NucleicAlphabet> GCContent for SeqSlice<'_, A> {
+    fn gc_content(&self) -> f64 {
+        let numer = self.chars().filter(|ch| A::is_gc(*ch)).count() as f64;
+        let denom = self.len() as f64;
+
+        numer / denom
+    }
+}
+
+impl<'a, A: CodingAlphabet> GeneticSequence for SeqSlice<'a, A> {
+    type Codon = A::Codon;
+
+    fn nucleotides(&self) -> SliceChars<'_> {
+        self.chars()
+    }
+}
+
+impl<'a, A: CodingAlphabet> TryFrom<SeqSlice<'a, A>> for ProteinSequence {
+    type Error = Error;
+
+    fn try_from(value: SeqSlice<'a, A>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<'a, A: CodingAlphabet> TryFrom<&SeqSlice<'a, A>> for ProteinSequence {
+    type Error = Error;
+
+    fn try_from(value: &SeqSlice<'a, A>) -> Result<Self, Self::Error> {
+        value.to_protein()
+    }
+}
+
+/// The symbols of a [SeqSlice], complemented and reversed on the reverse
+/// strand.
+#[derive(Debug, Clone)]
+pub struct SliceChars<'a> {
+    chars: Chars<'a>,
+    complement: Option<fn(char) -> char>,
+}
+
+impl<'a> SliceChars<'a> {
+    pub(crate) fn forward(chars: Chars<'a>) -> Self {
+        Self {
+            chars,
+            complement: None,
+        }
+    }
+}
+
+impl Iterator for SliceChars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.complement {
+            None => self.chars.next(),
+            Some(complement) => self.chars.next_back().map(complement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::DNASequence;
+
+    use super::*;
+
+    fn parent() -> DNASequence {
+        DNASequence::from_str("AAGCTTATGCCCTGAGG").unwrap()
+    }
+
+    #[test]
+    fn tracks_coordinates_on_both_strands() {
+        let seq = parent();
+        let slice = seq.slice(2..9).unwrap();
+        assert_eq!(slice.as_str(), Some("GCTTATG"));
+        assert_eq!(
+            (slice.start(), slice.end(), slice.strand()),
+            (2, 9, Strand::Forward)
+        );
+
+        let rev = slice.reverse_complement();
+        assert_eq!(rev.to_string(), "CATAAGC");
+        assert_eq!(rev.as_str(), None);
+        assert_eq!(
+            (rev.start(), rev.end(), rev.strand()),
+            (2, 9, Strand::Reverse)
+        );
+        assert_eq!(rev.reverse_complement(), slice);
+
+        // sub-slices of the reverse strand count back from the end
+        let sub = rev.slice(0..3).unwrap();
+        assert_eq!(sub.to_string(), "CAT");
+        assert_eq!(sub.range(), 6..9);
+
+        assert!(slice.slice(3..8).is_none());
+        assert!(seq.slice(10..20).is_none());
+    }
+
+    #[test]
+    fn agrees_with_owned_sequences() {
+        let seq = parent();
+        let rev = seq.as_slice().reverse_complement();
+        assert_eq!(rev.to_sequence(), seq.reverse_complement());
+        assert!((rev.gc_content() - seq.gc_content()).abs() < f64::EPSILON);
+
+        let motif = DNASequence::from_str("CC").unwrap();
+        assert_eq!(
+            rev.motif_lcoations(&motif),
+            seq.reverse_complement().motif_lcoations(&motif)
+        );
+    }
+
+    #[test]
+    fn finds_motifs_in_parent_coordinates() {
+        let seq = parent();
+        let motif = DNASequence::from_str("AGG").unwrap();
+
+        let hits = seq.as_slice().motif_hits(&motif);
+        assert_eq!(
+            hits.iter().map(|h| h.range()).collect::<Vec<_>>(),
+            vec![14..17]
+        );
+
+        // CCT on the reverse strand is AGG on the forward one
+        let motif = DNASequence::from_str("CCT").unwrap();
+        let hits = seq.as_slice().reverse_complement().motif_hits(&motif);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].range(), 14..17);
+        assert_eq!(hits[0].strand(), Strand::Reverse);
+    }
+
+    #[test]
+    fn counts_mismatches_exactly_unless_asked() {
+        use crate::{AmbiguousDNASequence, AmbiguousDna};
+
+        let seq = parent();
+        let slice = seq.slice(2..9).unwrap();
+        let other = DNASequence::from_str("GCATATC").unwrap();
+        assert_eq!(
+            slice.hamming_distance(&other).unwrap(),
+            slice.to_sequence().hamming_distance(&other).unwrap()
+        );
+
+        let seq = AmbiguousDNASequence::from_str("GCTTATG").unwrap();
+        let motif = Sequence::<AmbiguousDna>::from_str("GCNTATK").unwrap();
+        assert_eq!(seq.as_slice().hamming_distance(&motif).unwrap(), 2);
+        assert_eq!(seq.hamming_distance(&motif).unwrap(), 2);
+        assert_eq!(seq.as_slice().motif_mismatches(&motif).unwrap(), 0);
+        assert_eq!(seq.as_slice().motif_mismatches(&other.into()).unwrap(), 2);
+    }
+
+    #[test]
+    fn hamming_distance_does_not_expand_ambiguity_codes() {
+        use crate::AmbiguousDNASequence;
+
+        let seq = AmbiguousDNASequence::from_str("NCGT").unwrap();
+        let other = AmbiguousDNASequence::from_str("ACGT").unwrap();
+        assert_eq!(seq.hamming_distance(&other).unwrap(), 1);
+        assert_eq!(seq.as_slice().hamming_distance(&other).unwrap(), 1);
+        assert_eq!(other.as_slice().motif_mismatches(&seq).unwrap(), 0);
+    }
+
+    #[test]
+    fn translates_open_frames() {
+        let seq = parent();
+        let frames = seq.as_slice().open_frames();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].range(), 6..17);
+        assert_eq!(
+            ProteinSequence::try_from(frames[0]).ok(),
+            ProteinSequence::from_str("MP").ok()
+        );
+
+        // the reverse strand has ATG at forward positions 0..3, read backwards
+        let seq = DNASequence::from_str("CATTTCGGGTTA").unwrap();
+        let frames = seq.as_slice().reverse_complement().open_frames();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].range(), 0..3);
+        assert_eq!(frames[0].to_string(), "ATG");
+    }
+
+    #[test]
+    fn translates_with_other_genetic_codes() {
+        // GTG only starts a protein in bacteria, and TGA is tryptophan in
+        // mitochondria
+        let seq = DNASequence::from_str("CCGTGAAATGATGATTAGA").unwrap();
+        let bacterial = GeneticCode::from_id(11).unwrap();
+
+        let frames = seq.open_frames_with(&bacterial);
+        assert_eq!(
+            frames.iter().map(|f| f.start()).collect::<Vec<_>>(),
+            vec![2, 7, 10, 13]
+        );
+        assert_eq!(
+            frames[0].to_protein_with(&bacterial).ok(),
+            ProteinSequence::from_str("MK").ok()
+        );
+        assert_eq!(seq.open_frames().len(), 2);
+
+        let mito = GeneticCode::from_id(2).unwrap();
+        let seq = DNASequence::from_str("ATGTGAAGATAA").unwrap();
+        assert_eq!(
+            seq.to_protein_with(&mito).ok(),
+            ProteinSequence::from_str("MW").ok()
+        );
+        assert_eq!(seq.to_protein().ok(), ProteinSequence::from_str("M").ok());
+    }
+}
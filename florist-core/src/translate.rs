@@ -0,0 +1,326 @@
+//! Translating nucleotide sequences into proteins in more ways than
+//! Rosalind's problems need.
+use serde::Serialize;
+
+use crate::{
+    codon::Codon, AminoAcid, CodingAlphabet, Error, GeneticCode, GeneticSequence, Sequence, Strand,
+    TranslatedSequence,
+};
+
+/// Where translation begins.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum StartHandling {
+    /// The first codon must be a start codon.
+    Require,
+
+    /// Skip ahead to the first start codon.
+    #[default]
+    SkipToFirst,
+
+    /// Translate from the first codon, whatever it is.
+    Ignore,
+}
+
+/// What a stop codon does to translation.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum StopHandling {
+    /// End the protein, which must reach a stop.
+    #[default]
+    Terminate,
+
+    /// Carry on, writing the stop as `*`.
+    Emit,
+
+    /// Fail, for sequences that should not have any stops.
+    Error,
+}
+
+/// How [GeneticSequence::translate] reads a sequence.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TranslationOptions {
+    pub genetic_code: GeneticCode,
+    pub start: StartHandling,
+    pub stop: StopHandling,
+
+    /// Check the sequence is a whole coding sequence: a whole number of
+    /// codons, from a start codon to a single stop at the very end. This
+    /// replaces the start and stop handling.
+    pub strict_cds: bool,
+}
+
+/// Reads from the first `ATG` to the first stop in the standard code, as
+/// Rosalind does.
+impl Default for TranslationOptions {
+    fn default() -> Self {
+        Self {
+            genetic_code: GeneticCode::STANDARD.without_alternative_starts(),
+            start: StartHandling::default(),
+            stop: StopHandling::default(),
+            strict_cds: false,
+        }
+    }
+}
+
+/// The conceptual translation of one reading frame.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct FrameTranslation {
+    pub strand: Strand,
+
+    /// How many bases from the start of its strand the frame begins, which is
+    /// 0, 1 or 2.
+    pub frame: usize,
+
+    /// Every whole codon of the frame, with stops as `*`.
+    pub protein: TranslatedSequence,
+}
+
+impl<A: CodingAlphabet> Sequence<A> {
+    /// Translates the three frames of each strand, forward strand first.
+    /// Frames without a whole codon are left out.
+    pub fn six_frames(&self, code: &GeneticCode) -> Result<Vec<FrameTranslation>, Error> {
+        let forward = self.as_slice();
+        let mut translations = Vec::with_capacity(6);
+
+        for strand in [forward, forward.reverse_complement()] {
+            // frames are in order of offset, and only the last ones can be
+            // too short to exist
+            for (frame, slice) in strand.frames().into_iter().enumerate() {
+                if slice.len() < 3 {
+                    continue;
+                }
+
+                translations.push(FrameTranslation {
+                    strand: slice.strand(),
+                    frame,
+                    protein: slice.translate_frame(code)?,
+                });
+            }
+        }
+
+        Ok(translations)
+    }
+}
+
+/// Translates the sequence as the options say. Positions in errors count
+/// nucleotides from the start of the sequence as it is read.
+pub(crate) fn translate<S: GeneticSequence + ?Sized>(
+    seq: &S,
+    options: &TranslationOptions,
+) -> Result<TranslatedSequence, Error> {
+    if options.strict_cds {
+        return translate_cds(seq, &options.genetic_code);
+    }
+
+    let code = &options.genetic_code;
+    let mut codons = seq.codons().enumerate();
+    let mut output = String::new();
+
+    match options.start {
+        StartHandling::Require => match codons.next() {
+            Some((_, first)) => output.push(start(code, &first)?),
+            None => return Err(Error::NoStartCodon),
+        },
+        StartHandling::SkipToFirst => loop {
+            let (_, cur) = codons.next().ok_or(Error::NoStartCodon)?;
+            code.translate(&cur)?;
+            if code.is_start(&cur) {
+                output.push(AminoAcid::Methionine.abbreviation());
+                break;
+            }
+        },
+        StartHandling::Ignore => {}
+    }
+
+    for (i, cur) in codons {
+        let acid = code.translate(&cur)?;
+        if acid.is_stop() {
+            match options.stop {
+                StopHandling::Terminate => return Ok(TranslatedSequence::new_unchecked(output)),
+                StopHandling::Emit => {}
+                StopHandling::Error => return Err(Error::UnexpectedStopCodon(3 * i)),
+            }
+        }
+
+        output.push(acid.symbol());
+    }
+
+    match options.stop {
+        StopHandling::Terminate => Err(Error::MissingStopCodon),
+        _ => Ok(TranslatedSequence::new_unchecked(output)),
+    }
+}
+
+fn translate_cds<S: GeneticSequence + ?Sized>(
+    seq: &S,
+    code: &GeneticCode,
+) -> Result<TranslatedSequence, Error> {
+    let len = seq.nucleotides().count();
+    if !len.is_multiple_of(3) {
+        return Err(Error::IncompleteCodon(len));
+    }
+
+    let mut codons = seq.codons().enumerate();
+    let mut output = String::new();
+    match codons.next() {
+        Some((_, first)) => output.push(start(code, &first)?),
+        None => return Err(Error::NoStartCodon),
+    }
+
+    for (i, cur) in codons {
+        let acid = code.translate(&cur)?;
+        match (acid.is_stop(), 3 * (i + 1) == len) {
+            (true, true) => return Ok(TranslatedSequence::new_unchecked(output)),
+            (true, false) => return Err(Error::InternalStopCodon(3 * i)),
+            (false, _) => output.push(acid.symbol()),
+        }
+    }
+
+    Err(Error::MissingStopCodon)
+}
+
+/// The first residue of a protein starting at the codon, which is always
+/// methionine.
+fn start<C: Codon>(code: &GeneticCode, codon: &C) -> Result<char, Error> {
+    code.translate(codon)?;
+    if !code.is_start(codon) {
+        let (first, second, third) = codon.bases();
+        return Err(Error::MissingStartCodon(format!(
+            "{}{}{}",
+            first, second, third
+        )));
+    }
+
+    Ok(AminoAcid::Methionine.abbreviation())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{DNASequence, ProteinSequence, RNASequence};
+
+    use super::*;
+
+    #[test]
+    fn translates_whole_frames() {
+        let seq = RNASequence::from_str("CCAUGUAAGGUGAC").unwrap();
+        assert_eq!(
+            seq.translate_frame(&GeneticCode::STANDARD)
+                .unwrap()
+                .as_str(),
+            "PCKV"
+        );
+        // the AUG is out of frame, so there is no protein to find
+        assert!(seq.to_protein().is_err());
+
+        // only ATG starts by default, not the standard code's TTG and CTG
+        let seq = DNASequence::from_str("TTGGCCATGAAATAA").unwrap();
+        assert_eq!(seq.to_protein().unwrap().as_str(), "MK");
+        let seq = RNASequence::from_str("CUGGCCAUGAAAUAA").unwrap();
+        assert_eq!(seq.to_protein().unwrap().as_str(), "MK");
+
+        // stops are kept, so the frame is not a protein
+        let protein = DNASequence::from_str("ATGTAAATG")
+            .unwrap()
+            .translate_frame(&GeneticCode::STANDARD)
+            .unwrap();
+        assert_eq!(protein.as_str(), "M*M");
+        assert_eq!(TranslatedSequence::from_str("M*M").ok(), Some(protein));
+        assert!(ProteinSequence::from_str("M*M").is_err());
+    }
+
+    #[test]
+    fn translates_six_frames() {
+        let seq = DNASequence::from_str("ATGGCCTGAT").unwrap();
+        let frames = seq.six_frames(&GeneticCode::STANDARD).unwrap();
+
+        let found: Vec<_> = frames
+            .iter()
+            .map(|t| (t.strand, t.frame, t.protein.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Strand::Forward, 0, "MA*"),
+                (Strand::Forward, 1, "WPD"),
+                (Strand::Forward, 2, "GL"),
+                (Strand::Reverse, 0, "IRP"),
+                (Strand::Reverse, 1, "SGH"),
+                (Strand::Reverse, 2, "QA"),
+            ]
+        );
+
+        // too short for any codon in the later frames
+        let seq = DNASequence::from_str("ATGC").unwrap();
+        assert_eq!(seq.six_frames(&GeneticCode::STANDARD).unwrap().len(), 4);
+    }
+
+    fn with(start: StartHandling, stop: StopHandling) -> TranslationOptions {
+        TranslationOptions {
+            start,
+            stop,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn handles_starts() {
+        let seq = DNASequence::from_str("CCCATGGCCTAA").unwrap();
+
+        let protein = seq.translate(&with(StartHandling::SkipToFirst, StopHandling::Terminate));
+        assert_eq!(protein.unwrap().as_str(), "MA");
+        let protein = seq.translate(&with(StartHandling::Ignore, StopHandling::Terminate));
+        assert_eq!(protein.unwrap().as_str(), "PMA");
+        assert!(matches!(
+            seq.translate(&with(StartHandling::Require, StopHandling::Terminate)),
+            Err(Error::MissingStartCodon(codon)) if codon == "CCC"
+        ));
+
+        let seq = DNASequence::from_str("CCCGCCTAA").unwrap();
+        assert!(matches!(seq.to_protein(), Err(Error::NoStartCodon)));
+    }
+
+    #[test]
+    fn handles_stops() {
+        let seq = DNASequence::from_str("ATGTAAGCCTGAGC").unwrap();
+
+        let protein = seq.translate(&with(StartHandling::Require, StopHandling::Emit));
+        assert_eq!(protein.unwrap().as_str(), "M*A*");
+        assert!(matches!(
+            seq.translate(&with(StartHandling::Require, StopHandling::Error)),
+            Err(Error::UnexpectedStopCodon(3))
+        ));
+
+        // trimmed sequences translate to the end without a stop
+        let seq = DNASequence::from_str("ATGGCC").unwrap();
+        let protein = seq.translate(&with(StartHandling::Require, StopHandling::Error));
+        assert_eq!(protein.unwrap().as_str(), "MA");
+        assert!(matches!(seq.to_protein(), Err(Error::MissingStopCodon)));
+    }
+
+    #[test]
+    fn checks_coding_sequences() {
+        let strict = TranslationOptions {
+            strict_cds: true,
+            ..Default::default()
+        };
+        let cds = |raw: &str| DNASequence::from_str(raw).unwrap().translate(&strict);
+
+        assert_eq!(cds("ATGGCCTAA").unwrap().as_str(), "MA");
+        assert!(matches!(cds("ATGGCCTAAG"), Err(Error::IncompleteCodon(10))));
+        assert!(matches!(cds("GCCATGTAA"), Err(Error::MissingStartCodon(_))));
+        assert!(matches!(
+            cds("ATGTAAGCCTAA"),
+            Err(Error::InternalStopCodon(3))
+        ));
+        assert!(matches!(cds("ATGGCC"), Err(Error::MissingStopCodon)));
+
+        // alternative starts count in codes that have them
+        let bacterial = TranslationOptions {
+            genetic_code: GeneticCode::from_id(11).unwrap(),
+            ..strict
+        };
+        let seq = DNASequence::from_str("GTGGCCTAA").unwrap();
+        assert_eq!(seq.translate(&bacterial).unwrap().as_str(), "MA");
+    }
+}
@@ -1,9 +1,13 @@
 use std::{
     collections::HashMap,
+    fmt::{self, Display},
     ops::{Deref, DerefMut},
     str::FromStr,
 };
 
+/// The number of sequence characters written per line.
+const LINE_WIDTH: usize = 60;
+
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("No sequences defined in file")]
@@ -73,6 +77,16 @@ impl FromStr for MultiFasta {
     }
 }
 
+impl Display for MultiFasta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (description, sequence) in self.iter() {
+            write_record(f, description, sequence)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct OrderedMultiFasta(Vec<(String, String)>);
 
@@ -133,6 +147,34 @@ impl FromStr for OrderedMultiFasta {
     }
 }
 
+impl Display for OrderedMultiFasta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (description, sequence) in self.iter() {
+            write_record(f, description, sequence)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_record(f: &mut fmt::Formatter<'_>, description: &str, sequence: &str) -> fmt::Result {
+    writeln!(f, ">{}", description)?;
+    // nothing checks sequences are ASCII, so count characters rather than
+    // bytes to never split one
+    let mut rest = sequence;
+    while !rest.is_empty() {
+        let end = rest
+            .char_indices()
+            .nth(LINE_WIDTH)
+            .map_or(rest.len(), |(i, _)| i);
+        let (line, tail) = rest.split_at(end);
+        writeln!(f, "{}", line)?;
+        rest = tail;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -188,4 +230,38 @@ ATATCCATTTGTCAGCAGACACGC";
         let parsed = MultiFasta::from_str(input);
         assert_eq!(parsed, Err(Error::NoSequences));
     }
+
+    #[test]
+    fn display_round_trips() {
+        let mut fasta = OrderedMultiFasta::default();
+        fasta.push(("Rosalind_1".to_string(), "ACGT".repeat(20)));
+        fasta.push(("Rosalind_2".to_string(), "TTGA".to_string()));
+
+        let displayed = fasta.to_string();
+        assert_eq!(
+            displayed,
+            format!(
+                ">Rosalind_1\n{}\n{}\n>Rosalind_2\nTTGA\n",
+                &fasta[0].1[..60],
+                &fasta[0].1[60..]
+            )
+        );
+        assert_eq!(OrderedMultiFasta::from_str(&displayed), Ok(fasta));
+    }
+
+    #[test]
+    fn display_keeps_multibyte_characters_whole() {
+        let mut fasta = OrderedMultiFasta::default();
+        fasta.push((
+            "Rosalind_1".to_string(),
+            format!("{}é{}", "A".repeat(59), "C".repeat(60)),
+        ));
+
+        let displayed = fasta.to_string();
+        assert_eq!(
+            displayed.lines().nth(1),
+            Some(format!("{}é", "A".repeat(59)).as_str())
+        );
+        assert_eq!(OrderedMultiFasta::from_str(&displayed), Ok(fasta));
+    }
 }
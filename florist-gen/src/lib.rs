@@ -0,0 +1,257 @@
+//! Building blocks for generating random, valid datasets.
+//!
+//! Problems use these from their `Problem::generate`
+//! implementations, and they are handy on their own for property tests.
+use florist_core::{Alphabet, DNASequence, Sequence};
+use florist_fasta::OrderedMultiFasta;
+use rand::{seq::index, Rng};
+
+/// Rosalind record descriptions carry a four digit number, so this is the
+/// most records a single dataset can have.
+pub const MAX_RECORDS: usize = 10_000;
+
+const STOP_CODONS: [&str; 3] = ["TAA", "TAG", "TGA"];
+
+/// Makes a random sequence of the given length from the symbols of `A`.
+///
+/// Empty sequences are never valid input, so the length is at least one.
+pub fn sequence<A: Alphabet, R: Rng + ?Sized>(rng: &mut R, len: usize) -> Sequence<A> {
+    let symbols = A::SYMBOLS.as_bytes();
+    let raw = (0..len.max(1))
+        .map(|_| symbols[rng.gen_range(0..symbols.len())] as char)
+        .collect();
+
+    Sequence::new_unchecked(raw)
+}
+
+/// Makes a sequence that translates cleanly into a protein of `codons`
+/// amino acids: a start codon, `codons - 1` random non-stop codons and a
+/// final stop codon.
+pub fn coding_sequence<R: Rng + ?Sized>(rng: &mut R, codons: usize) -> DNASequence {
+    let mut raw = String::with_capacity((codons.max(1) + 1) * 3);
+    raw.push_str("ATG");
+
+    while raw.len() < codons.max(1) * 3 {
+        let codon: DNASequence = sequence(rng, 3);
+        if !STOP_CODONS.contains(&codon.as_str()) {
+            raw.push_str(&codon);
+        }
+    }
+
+    raw.push_str(STOP_CODONS[rng.gen_range(0..STOP_CODONS.len())]);
+
+    DNASequence::new_unchecked(raw)
+}
+
+/// Substitutes each position of the sequence with a different base with the
+/// given probability.
+pub fn mutate<R: Rng + ?Sized>(rng: &mut R, seq: &DNASequence, rate: f64) -> DNASequence {
+    let symbols = DNASequence::SYMBOLS.as_bytes();
+    let raw = seq
+        .bytes()
+        .map(|base| {
+            if !rng.gen_bool(rate) {
+                return base as char;
+            }
+
+            loop {
+                let other = symbols[rng.gen_range(0..symbols.len())];
+                if other != base {
+                    return other as char;
+                }
+            }
+        })
+        .collect();
+
+    DNASequence::new_unchecked(raw)
+}
+
+/// Copies the sequence with random edits, each position being substituted,
+/// deleted or having a symbol inserted before it with the given probability.
+/// This makes pairs of related sequences for alignment, of any alphabet.
+///
+/// Empty sequences are never valid input, so at least one symbol is kept.
+pub fn evolve<A: Alphabet, R: Rng + ?Sized>(
+    rng: &mut R,
+    seq: &Sequence<A>,
+    rate: f64,
+) -> Sequence<A> {
+    let symbols = A::SYMBOLS.as_bytes();
+    let random = |rng: &mut R| symbols[rng.gen_range(0..symbols.len())] as char;
+
+    let mut raw = String::with_capacity(seq.len());
+    for ch in seq.chars() {
+        if !rng.gen_bool(rate) {
+            raw.push(ch);
+            continue;
+        }
+
+        match rng.gen_range(0..3) {
+            0 => raw.push(random(rng)),
+            1 => {}
+            _ => {
+                raw.push(random(rng));
+                raw.push(ch);
+            }
+        }
+    }
+
+    if raw.is_empty() {
+        raw.push(random(rng));
+    }
+
+    Sequence::new_unchecked(raw)
+}
+
+/// Makes a sequence sharing an evolved stretch of the given one, between
+/// unrelated flanks half its length in all. This makes pairs of sequences
+/// for local alignment.
+pub fn local_relative<A: Alphabet, R: Rng + ?Sized>(rng: &mut R, seq: &Sequence<A>) -> Sequence<A> {
+    let start = rng.gen_range(0..seq.len());
+    let end = rng.gen_range((start + 1)..=seq.len());
+    let rate = rng.gen_range(0.0..0.5);
+    let shared = evolve(rng, &seq.subsequence_unchecked(start, end), rate);
+    let flank: Sequence<A> = sequence(rng, seq.len() / 2);
+    let (left, right) = flank.split_at(flank.len() / 2);
+
+    Sequence::new_unchecked(format!("{}{}{}", left, shared, right))
+}
+
+/// Gives each sequence a unique, Rosalind style description.
+///
+/// # Panics
+/// If there are more than [MAX_RECORDS] sequences.
+pub fn fasta<R, I>(rng: &mut R, sequences: I) -> OrderedMultiFasta
+where
+    R: Rng + ?Sized,
+    I: IntoIterator,
+    I::Item: ToString,
+{
+    let sequences: Vec<_> = sequences.into_iter().map(|s| s.to_string()).collect();
+    let ids = index::sample(rng, MAX_RECORDS, sequences.len());
+
+    let mut records = OrderedMultiFasta::default();
+    for (id, seq) in ids.into_iter().zip(sequences) {
+        records.push((format!("Rosalind_{:04}", id), seq));
+    }
+
+    records
+}
+
+/// Makes `count` random sequences of the given length, with unique
+/// descriptions.
+pub fn random_fasta<A: Alphabet, R: Rng + ?Sized>(
+    rng: &mut R,
+    count: usize,
+    len: usize,
+) -> OrderedMultiFasta {
+    let sequences: Vec<Sequence<A>> = (0..count).map(|_| sequence(rng, len)).collect();
+    fasta(rng, sequences)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_core::{Dna, HammingDistance, ProteinSequence, RNASequence};
+    use florist_fasta::MultiFasta;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    /// Runs the property against a spread of seeds and sizes.
+    fn check<F: FnMut(&mut StdRng, usize)>(mut property: F) {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            for size in [1, 2, 3, 10, 100, 1000] {
+                property(&mut rng, size);
+            }
+        }
+    }
+
+    #[test]
+    fn sequences_are_valid() {
+        check(|rng, size| {
+            let seq: DNASequence = sequence(rng, size);
+            assert_eq!(seq.len(), size);
+            assert_eq!(DNASequence::from_str(&seq).ok(), Some(seq));
+
+            let seq: ProteinSequence = sequence(rng, size);
+            assert_eq!(ProteinSequence::from_str(&seq).ok(), Some(seq));
+        });
+    }
+
+    #[test]
+    fn reverse_complement_is_an_involution() {
+        check(|rng, size| {
+            let seq: DNASequence = sequence(rng, size);
+            assert_eq!(seq.reverse_complement().reverse_complement(), seq);
+            assert_eq!(
+                seq.reverse_complement().chars().rev().collect::<String>(),
+                *seq.complement()
+            );
+        });
+    }
+
+    #[test]
+    fn transcription_round_trips() {
+        check(|rng, size| {
+            let seq: DNASequence = sequence(rng, size);
+            let rna = RNASequence::from(seq.clone());
+            assert!(!rna.contains('T'));
+            assert_eq!(DNASequence::from(rna), seq);
+        });
+    }
+
+    #[test]
+    fn mutations_bound_hamming_distance() {
+        check(|rng, size| {
+            let seq: DNASequence = sequence(rng, size);
+            assert_eq!(seq.hamming_distance(&mutate(rng, &seq, 0.0)).ok(), Some(0));
+            assert_eq!(
+                seq.hamming_distance(&mutate(rng, &seq, 1.0)).ok(),
+                Some(size as u64)
+            );
+
+            let other = mutate(rng, &seq, 0.3);
+            assert_eq!(
+                seq.hamming_distance(&other).ok(),
+                other.hamming_distance(&seq).ok()
+            );
+        });
+    }
+
+    #[test]
+    fn evolved_sequences_are_valid() {
+        check(|rng, size| {
+            let seq: ProteinSequence = sequence(rng, size);
+            assert_eq!(evolve(rng, &seq, 0.0), seq);
+
+            let other = evolve(rng, &seq, 0.5);
+            assert_eq!(ProteinSequence::from_str(&other).ok(), Some(other.clone()));
+            assert!(other.len() <= 2 * size);
+        });
+    }
+
+    #[test]
+    fn coding_sequences_translate() {
+        check(|rng, size| {
+            let seq = coding_sequence(rng, size);
+            let protein = ProteinSequence::try_from(&seq).expect("Failed to translate");
+            assert_eq!(protein.len(), size);
+            assert!(protein.starts_with('M'));
+        });
+    }
+
+    #[test]
+    fn fasta_round_trips() {
+        check(|rng, size| {
+            let records = random_fasta::<Dna, _>(rng, 5, size);
+            let parsed = MultiFasta::from_str(&records.to_string()).expect("Failed to parse");
+            assert_eq!(parsed.len(), 5);
+            for (desc, seq) in records.iter() {
+                assert_eq!(parsed.get(desc), Some(seq));
+            }
+        });
+    }
+}
@@ -4,29 +4,29 @@ use std::{
     str::FromStr,
 };
 
-use florist_core::{DNASequence, ProteinSequence, RNASequence, Sequence};
+use florist_core::{Alphabet, Dna, Protein, Rna, Sequence};
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub struct SequenceList<T>(Vec<T>)
+pub struct SequenceList<A>(Vec<Sequence<A>>)
 where
-    T: Sequence;
+    A: Alphabet;
 
-impl<T: Sequence> Deref for SequenceList<T> {
-    type Target = Vec<T>;
+impl<A: Alphabet> Deref for SequenceList<A> {
+    type Target = Vec<Sequence<A>>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-impl<T: Sequence> DerefMut for SequenceList<T> {
+impl<A: Alphabet> DerefMut for SequenceList<A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.0
     }
 }
 
-impl<T: Sequence> FromStr for SequenceList<T> {
-    type Err = <T as FromStr>::Err;
+impl<A: Alphabet> FromStr for SequenceList<A> {
+    type Err = <Sequence<A> as FromStr>::Err;
 
     fn from_str(sequences: &str) -> Result<Self, Self::Err> {
         Ok(Self(
@@ -34,12 +34,12 @@ impl<T: Sequence> FromStr for SequenceList<T> {
                 .trim()
                 .split("\n")
                 .map(|s| s.trim().parse())
-                .collect::<Result<Vec<T>, _>>()?,
+                .collect::<Result<Vec<_>, _>>()?,
         ))
     }
 }
 
-impl<T: Sequence> Display for SequenceList<T> {
+impl<A: Alphabet> Display for SequenceList<A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let out = self
             .iter()
@@ -53,10 +53,10 @@ impl<T: Sequence> Display for SequenceList<T> {
 // convenience wrappers
 
 /// A list of DNA sequences built from a newline-separated input string
-pub type DNASequenceList = SequenceList<DNASequence>;
+pub type DNASequenceList = SequenceList<Dna>;
 
 /// A list of RNA sequences built from a newline-separated input string
-pub type RNASequenceList = SequenceList<RNASequence>;
+pub type RNASequenceList = SequenceList<Rna>;
 
 /// A list of protein sequences built from a newline-separated input string
-pub type ProteinSequenceList = SequenceList<ProteinSequence>;
+pub type ProteinSequenceList = SequenceList<Protein>;
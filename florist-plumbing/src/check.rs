@@ -0,0 +1,380 @@
+//! Tools for comparing a solution against an expected answer the same way
+//! Rosalind would, rather than byte for byte.
+use std::{collections::HashMap, fmt::Display};
+
+use crate::Problem;
+
+/// How the lines of a problem's output relate to each other.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum OutputOrder {
+    /// Lines must appear in the same order as the expected answer.
+    #[default]
+    Ordered,
+
+    /// The output is a set of lines, which may appear in any order.
+    Unordered,
+
+    /// Only the first `n` lines must match, in order. The rest is one of
+    /// several right answers, like an alignment achieving the score on the
+    /// first line.
+    FirstLines(usize),
+}
+
+/// How far apart two numeric tokens may be while still being considered equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// The absolute difference may be at most this value.
+    Absolute(f64),
+
+    /// The difference may be at most this fraction of the larger magnitude.
+    Relative(f64),
+}
+
+impl Tolerance {
+    pub fn accepts(&self, actual: f64, expected: f64) -> bool {
+        let diff = (actual - expected).abs();
+        match self {
+            Self::Absolute(tol) => diff <= *tol,
+            Self::Relative(tol) => diff <= tol * actual.abs().max(expected.abs()),
+        }
+    }
+}
+
+impl Default for Tolerance {
+    /// Rosalind accepts floating point answers within an absolute error of
+    /// 0.001.
+    fn default() -> Self {
+        Self::Absolute(0.001)
+    }
+}
+
+/// A single disagreement between an actual and an expected output.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Difference {
+    /// The line at this (1-based) position differs.
+    Changed {
+        line: usize,
+        expected: String,
+        actual: String,
+    },
+
+    /// The expected line is missing from the output.
+    Missing {
+        line: Option<usize>,
+        expected: String,
+    },
+
+    /// The output has a line that was not expected.
+    Unexpected { line: Option<usize>, actual: String },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Changed {
+                line,
+                expected,
+                actual,
+            } => write!(f, "line {}:\n  - {}\n  + {}", line, expected, actual),
+            Self::Missing {
+                line: Some(line),
+                expected,
+            } => write!(f, "line {}:\n  - {}", line, expected),
+            Self::Missing {
+                line: None,
+                expected,
+            } => write!(f, "missing:\n  - {}", expected),
+            Self::Unexpected {
+                line: Some(line),
+                actual,
+            } => write!(f, "line {}:\n  + {}", line, actual),
+            Self::Unexpected { line: None, actual } => write!(f, "unexpected:\n  + {}", actual),
+        }
+    }
+}
+
+/// Every disagreement found when checking an output.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mismatch(Vec<Difference>);
+
+impl Mismatch {
+    /// The maximum number of differences rendered by [Display].
+    const MAX_SHOWN: usize = 20;
+
+    pub fn differences(&self) -> &[Difference] {
+        &self.0
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} difference(s) from the expected output", self.0.len())?;
+        for diff in self.0.iter().take(Self::MAX_SHOWN) {
+            writeln!(f, "{}", diff)?;
+        }
+
+        if self.0.len() > Self::MAX_SHOWN {
+            writeln!(f, "... and {} more", self.0.len() - Self::MAX_SHOWN)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Compares outputs token by token, allowing numeric tokens to differ within
+/// a [Tolerance].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Checker {
+    pub order: OutputOrder,
+    pub tolerance: Tolerance,
+}
+
+impl Checker {
+    pub fn new(order: OutputOrder, tolerance: Tolerance) -> Self {
+        Self { order, tolerance }
+    }
+
+    /// Makes a checker using the output order declared by the given problem.
+    pub fn for_problem<P: Problem>() -> Self {
+        Self {
+            order: P::OUTPUT_ORDER,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: Tolerance) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Checks the actual output against the expected one, ignoring leading
+    /// and trailing whitespace as well as the amount of whitespace between
+    /// tokens.
+    pub fn check(&self, actual: &str, expected: &str) -> Result<(), Mismatch> {
+        let actual: Vec<_> = actual.trim().lines().collect();
+        let expected: Vec<_> = expected.trim().lines().collect();
+
+        let differences = match self.order {
+            OutputOrder::Ordered => self.check_ordered(&actual, &expected),
+            OutputOrder::Unordered => self.check_unordered(&actual, &expected),
+            OutputOrder::FirstLines(n) => self.check_ordered(
+                &actual[..n.min(actual.len())],
+                &expected[..n.min(expected.len())],
+            ),
+        };
+
+        if differences.is_empty() {
+            Ok(())
+        } else {
+            Err(Mismatch(differences))
+        }
+    }
+
+    fn check_ordered(&self, actual: &[&str], expected: &[&str]) -> Vec<Difference> {
+        let mut differences = Vec::new();
+
+        for i in 0..actual.len().max(expected.len()) {
+            match (actual.get(i), expected.get(i)) {
+                (Some(a), Some(e)) => {
+                    if !self.lines_match(a, e) {
+                        differences.push(Difference::Changed {
+                            line: i + 1,
+                            expected: e.to_string(),
+                            actual: a.to_string(),
+                        });
+                    }
+                }
+                (None, Some(e)) => differences.push(Difference::Missing {
+                    line: Some(i + 1),
+                    expected: e.to_string(),
+                }),
+                (Some(a), None) => differences.push(Difference::Unexpected {
+                    line: Some(i + 1),
+                    actual: a.to_string(),
+                }),
+                (None, None) => unreachable!(),
+            }
+        }
+
+        differences
+    }
+
+    fn check_unordered(&self, actual: &[&str], expected: &[&str]) -> Vec<Difference> {
+        // knock out the exact matches first, as those are cheap, and only
+        // attempt the tolerant pairing with whatever is left over.
+        let mut remaining: HashMap<String, usize> = HashMap::new();
+        for e in expected {
+            *remaining.entry(normalize(e)).or_default() += 1;
+        }
+
+        let mut leftover_actual = Vec::new();
+        for a in actual {
+            match remaining.get_mut(&normalize(a)) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => leftover_actual.push(*a),
+            }
+        }
+
+        let mut leftover_expected: Vec<_> = expected
+            .iter()
+            .filter(|e| match remaining.get_mut(&normalize(e)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            })
+            .copied()
+            .collect();
+
+        let mut differences = Vec::new();
+        for a in leftover_actual {
+            match leftover_expected
+                .iter()
+                .position(|e| self.lines_match(a, e))
+            {
+                Some(idx) => {
+                    leftover_expected.swap_remove(idx);
+                }
+                None => differences.push(Difference::Unexpected {
+                    line: None,
+                    actual: a.to_string(),
+                }),
+            }
+        }
+
+        differences.extend(leftover_expected.into_iter().map(|e| Difference::Missing {
+            line: None,
+            expected: e.to_string(),
+        }));
+
+        differences
+    }
+
+    fn lines_match(&self, actual: &str, expected: &str) -> bool {
+        let mut actual = actual.split_whitespace();
+        let mut expected = expected.split_whitespace();
+
+        loop {
+            match (actual.next(), expected.next()) {
+                (Some(a), Some(e)) => {
+                    if !self.tokens_match(a, e) {
+                        return false;
+                    }
+                }
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    fn tokens_match(&self, actual: &str, expected: &str) -> bool {
+        if actual == expected {
+            return true;
+        }
+
+        match (actual.parse::<f64>(), expected.parse::<f64>()) {
+            (Ok(a), Ok(e)) => self.tolerance.accepts(a, e),
+            _ => false,
+        }
+    }
+}
+
+fn normalize(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match() {
+        let checker = Checker::default();
+        assert!(checker.check("1 2 3\nfoo", "1 2 3\nfoo\n").is_ok());
+        assert!(checker.check("1  2 3\n  foo", "1 2 3\nfoo").is_ok());
+    }
+
+    #[test]
+    fn float_tolerance() {
+        let checker = Checker::default();
+        assert!(checker.check("0.7584388185654007", "0.758").is_ok());
+        assert!(checker.check("0.7584388185654007", "0.760").is_err());
+
+        let checker = checker.with_tolerance(Tolerance::Relative(1e-6));
+        assert!(checker.check("117772.67882000057", "117772.6788").is_ok());
+        assert!(checker.check("117772.67882000057", "117772.5").is_err());
+    }
+
+    #[test]
+    fn non_numeric_tokens_must_be_equal() {
+        let checker = Checker::default();
+        let res = checker.check("Rosalind_9913\n51.2396", "Rosalind_9914\n51.2396");
+        assert_eq!(
+            res,
+            Err(Mismatch(vec![Difference::Changed {
+                line: 1,
+                expected: "Rosalind_9914".to_string(),
+                actual: "Rosalind_9913".to_string(),
+            }]))
+        );
+    }
+
+    #[test]
+    fn ordered_reports_missing_and_extra_lines() {
+        let checker = Checker::default();
+        let res = checker.check("a\nb", "a\nb\nc").unwrap_err();
+        assert_eq!(
+            res.differences(),
+            &[Difference::Missing {
+                line: Some(3),
+                expected: "c".to_string()
+            }]
+        );
+
+        let res = checker.check("a\nc\nb", "a\nb").unwrap_err();
+        assert_eq!(res.differences().len(), 2);
+    }
+
+    #[test]
+    fn unordered() {
+        let checker = Checker::new(OutputOrder::Unordered, Tolerance::default());
+        assert!(checker.check("b\na\nc", "a\nb\nc").is_ok());
+        assert!(checker.check("b 0.5001\na", "a\nb 0.5").is_ok());
+
+        let res = checker.check("a\na\nd", "a\nb\na").unwrap_err();
+        assert_eq!(
+            res.differences(),
+            &[
+                Difference::Unexpected {
+                    line: None,
+                    actual: "d".to_string()
+                },
+                Difference::Missing {
+                    line: None,
+                    expected: "b".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn first_lines() {
+        let checker = Checker::new(OutputOrder::FirstLines(1), Tolerance::default());
+        assert!(checker.check("23\nLYPRT\nLEAS", "23\nMEANLY\nLEAS").is_ok());
+        assert!(checker.check("22\nLYPRT\nLEAS", "23\nLYPRT\nLEAS").is_err());
+        assert!(checker.check("", "23").is_err());
+    }
+
+    #[test]
+    fn mismatch_display() {
+        let checker = Checker::default();
+        let res = checker.check("a\nx", "a\nb\nc").unwrap_err();
+        assert_eq!(
+            res.to_string(),
+            "2 difference(s) from the expected output\nline 2:\n  - b\n  + x\nline 3:\n  - c\n"
+        );
+    }
+}
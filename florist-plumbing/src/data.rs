@@ -0,0 +1,293 @@
+//! Locates the fixed datasets and solutions used by the tests and benches, so
+//! they work regardless of the directory they are run from.
+//!
+//! Each kind of data is looked for, in order, in
+//! 1. the directory named by its environment variable (e.g. `FLORIST_INPUTS`),
+//! 2. its directory in this workspace,
+//! 3. the directory set in a `florist.toml` config file.
+//!
+//! The config file is the one named by `FLORIST_CONFIG`, or else the first
+//! `florist.toml` in the current directory or any of its ancestors. Relative
+//! paths in it are relative to the file itself.
+//!
+//! ```toml
+//! inputs = "data/inputs"
+//! solutions = "data/solutions"
+//! ```
+use std::{
+    env, fmt, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+/// The name of the config file searched for in the current directory and its
+/// ancestors.
+pub const CONFIG_FILE: &str = "florist.toml";
+
+/// The environment variable that overrides where the config file is.
+pub const CONFIG_VAR: &str = "FLORIST_CONFIG";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not find {kind} file {name:?}, tried:{}", Tried(.tried))]
+    NotFound {
+        kind: &'static str,
+        name: String,
+        tried: Vec<PathBuf>,
+    },
+
+    #[error("Failed to read {path:?}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+
+    #[error("Invalid config file {path:?}: {source}")]
+    Config {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+}
+
+/// Renders the tried paths one per line.
+struct Tried<'a>(&'a [PathBuf]);
+
+impl fmt::Display for Tried<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for path in self.0 {
+            write!(f, "\n  {}", path.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    inputs: Option<PathBuf>,
+    solutions: Option<PathBuf>,
+}
+
+impl Config {
+    fn dir(&self, key: &str) -> Option<&PathBuf> {
+        match key {
+            "inputs" => self.inputs.as_ref(),
+            "solutions" => self.solutions.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// A kind of data file, and the places to look for it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DataDir {
+    /// What the files are, for error messages.
+    pub kind: &'static str,
+
+    /// The environment variable naming the directory.
+    pub env_var: &'static str,
+
+    /// The directory relative to the workspace root.
+    pub workspace_dir: &'static str,
+
+    /// The key naming the directory in the config file.
+    pub config_key: &'static str,
+}
+
+/// The input datasets.
+pub const INPUTS: DataDir = DataDir {
+    kind: "input",
+    env_var: "FLORIST_INPUTS",
+    workspace_dir: "inputs",
+    config_key: "inputs",
+};
+
+/// The expected solutions for input datasets too large to inline.
+pub const SOLUTIONS: DataDir = DataDir {
+    kind: "solution",
+    env_var: "FLORIST_SOLUTIONS",
+    workspace_dir: "florist-solver/solutions",
+    config_key: "solutions",
+};
+
+impl DataDir {
+    /// Every directory that may hold these files, in the order they are
+    /// searched.
+    pub fn candidates(&self) -> Result<Vec<PathBuf>, Error> {
+        let mut dirs = self.fixed_candidates();
+        dirs.extend(self.config_candidate(load_config)?);
+        Ok(dirs)
+    }
+
+    /// The directories that don't need the config file.
+    fn fixed_candidates(&self) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+
+        if let Some(dir) = env::var_os(self.env_var) {
+            dirs.push(PathBuf::from(dir));
+        }
+
+        dirs.push(workspace_root().join(self.workspace_dir));
+        dirs
+    }
+
+    /// The directory set in the config file loaded by `load`, if any.
+    fn config_candidate<F>(&self, load: F) -> Result<Option<PathBuf>, Error>
+    where
+        F: FnOnce() -> Result<Option<(PathBuf, Config)>, Error>,
+    {
+        Ok(load()?.and_then(|(path, config)| {
+            let dir = config.dir(self.config_key)?;
+            // unwrap is safe as a file always has a parent
+            Some(path.parent().unwrap().join(dir))
+        }))
+    }
+
+    /// Finds the file with the given name in the first directory that has it.
+    /// The config file is only read if no earlier directory has the file.
+    pub fn locate(&self, name: &str) -> Result<PathBuf, Error> {
+        self.locate_with(name, load_config)
+    }
+
+    fn locate_with<F>(&self, name: &str, load: F) -> Result<PathBuf, Error>
+    where
+        F: FnOnce() -> Result<Option<(PathBuf, Config)>, Error>,
+    {
+        let mut tried = Vec::new();
+        for dir in self.fixed_candidates() {
+            let path = dir.join(name);
+            if path.is_file() {
+                return Ok(path);
+            }
+            tried.push(path);
+        }
+
+        if let Some(dir) = self.config_candidate(load)? {
+            let path = dir.join(name);
+            if path.is_file() {
+                return Ok(path);
+            }
+            tried.push(path);
+        }
+
+        Err(Error::NotFound {
+            kind: self.kind,
+            name: name.to_string(),
+            tried,
+        })
+    }
+
+    /// Reads the file with the given name, trimming surrounding whitespace.
+    pub fn load(&self, name: &str) -> Result<String, Error> {
+        let path = self.locate(name)?;
+        std::fs::read_to_string(&path)
+            .map(|s| s.trim().to_string())
+            .map_err(|source| Error::Io { path, source })
+    }
+}
+
+/// The root of the workspace this crate was built in.
+fn workspace_root() -> &'static Path {
+    // unwrap is safe as this crate is always one level below the root
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// Finds and parses the config file, if there is one.
+fn load_config() -> Result<Option<(PathBuf, Config)>, Error> {
+    let path = match env::var_os(CONFIG_VAR) {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let cwd = env::current_dir().map_err(|source| Error::Io {
+                path: PathBuf::from("."),
+                source,
+            })?;
+
+            match cwd
+                .ancestors()
+                .map(|dir| dir.join(CONFIG_FILE))
+                .find(|path| path.is_file())
+            {
+                Some(path) => path,
+                None => return Ok(None),
+            }
+        }
+    };
+
+    let raw = std::fs::read_to_string(&path).map_err(|source| Error::Io {
+        path: path.clone(),
+        source,
+    })?;
+    let config = toml::from_str(&raw).map_err(|source| Error::Config {
+        path: path.clone(),
+        source,
+    })?;
+
+    Ok(Some((path, config)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_workspace_inputs() {
+        let path = INPUTS.locate("rosalind_dna.txt").expect("Failed to locate");
+        assert!(path.ends_with("inputs/rosalind_dna.txt"));
+    }
+
+    #[test]
+    fn lists_every_path_tried() {
+        let err = SOLUTIONS.locate("no_such_file.txt").unwrap_err();
+        let tried = match &err {
+            Error::NotFound { tried, .. } => tried.clone(),
+            _ => panic!("Unexpected error: {}", err),
+        };
+
+        assert!(tried.contains(&workspace_root().join("florist-solver/solutions/no_such_file.txt")));
+
+        let msg = err.to_string();
+        assert!(msg.starts_with("Could not find solution file \"no_such_file.txt\", tried:\n"));
+        for path in tried {
+            assert!(msg.contains(&path.display().to_string()));
+        }
+    }
+
+    #[test]
+    fn reads_config_only_when_needed() {
+        let broken = || {
+            let source = toml::from_str::<Config>("inputs = ").unwrap_err();
+            Err(Error::Config {
+                path: PathBuf::from("florist.toml"),
+                source,
+            })
+        };
+
+        let path = INPUTS.locate_with("rosalind_dna.txt", broken).unwrap();
+        assert!(path.ends_with("inputs/rosalind_dna.txt"));
+        assert!(matches!(
+            INPUTS.locate_with("no_such_file.txt", broken),
+            Err(Error::Config { .. })
+        ));
+
+        let config = || {
+            let config = toml::from_str("inputs = \"elsewhere\"").unwrap();
+            Ok(Some((PathBuf::from("/data/florist.toml"), config)))
+        };
+        let Err(Error::NotFound { tried, .. }) = INPUTS.locate_with("no_such_file.txt", config)
+        else {
+            panic!("Expected the file not to be found");
+        };
+        assert_eq!(
+            tried.last(),
+            Some(&PathBuf::from("/data/elsewhere/no_such_file.txt"))
+        );
+    }
+
+    #[test]
+    fn parses_config() {
+        let config: Config = toml::from_str("inputs = \"a/b\"").unwrap();
+        assert_eq!(config.inputs, Some(PathBuf::from("a/b")));
+        assert_eq!(config.solutions, None);
+
+        assert!(toml::from_str::<Config>("input = \"a/b\"").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+/// Where the known-good answer for a problem's input dataset lives.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Solution {
+    /// The expected output, for answers short enough to keep inline.
+    Inline(&'static str),
+
+    /// The name of a file in the solutions directory holding the expected
+    /// output.
+    File(&'static str),
+}
+
+/// Describes a single Rosalind problem as it is known to the registry.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Descriptor {
+    /// The Rosalind problem ID, e.g. `DNA`.
+    pub id: &'static str,
+
+    /// The human-readable problem title, e.g. `Counting DNA Nucleotides`.
+    pub title: &'static str,
+
+    /// The kebab-case name of the crate (and cli subcommand) solving this
+    /// problem.
+    pub slug: &'static str,
+
+    /// The name of the input dataset in the inputs directory.
+    pub input_file: &'static str,
+
+    /// The expected solution for the input dataset.
+    pub solution: Solution,
+}
+
+/// A sample dataset and its expected output, as given in a problem statement.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Sample {
+    pub input: &'static str,
+    pub output: &'static str,
+}
@@ -1,4 +1,16 @@
-use std::{fmt::Display, io, path::Path, str::FromStr};
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+pub mod check;
+pub mod data;
+pub mod descriptor;
+
+pub use check::{Checker, Mismatch, OutputOrder, Tolerance};
+pub use descriptor::{Descriptor, Sample, Solution};
 
 /// Indicates the following structure represents a problem solution.
 pub trait Problem {
@@ -9,17 +21,94 @@ pub trait Problem {
     type Input: FromStr;
 
     /// The output type representing the solution to this problem.
+    ///
+    /// [Display] gives the answer as Rosalind expects it. Outputs that also
+    /// implement `serde::Serialize` can be written as JSON by the CLI, which
+    /// is opt-in so problems don't need serde to be solved.
     type Output: Display;
 
+    /// Whether the lines of the output must appear in a particular order.
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Ordered;
+
+    /// The sample dataset and expected output from the problem statement.
+    const SAMPLE: Option<Sample> = None;
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error>;
 
+    /// Produces a random, valid dataset for this problem, or `None` if the
+    /// problem does not know how to make one.
+    ///
+    /// The `size` is roughly the length of the main sequence(s), or the main
+    /// parameter for problems without sequences. Problems clamp it to the
+    /// range they accept, so any size may be asked for.
+    fn generate<R: Rng + ?Sized>(_rng: &mut R, _size: usize) -> Option<String> {
+        None
+    }
+
     /// Conveninece function leveraged by the solver to make inputs.
     fn input_from(val: &str) -> Result<Self::Input, <Self::Input as FromStr>::Err> {
         Self::Input::from_str(val)
     }
 }
 
-pub fn load_input_file(name: &str) -> Result<String, io::Error> {
-    let path = Path::new("../inputs").join(name);
-    std::fs::read_to_string(path).map(|s| s.trim().to_string())
+/// Solves the sample dataset declared by the given problem and panics if the
+/// solution does not match the sample output.
+///
+/// This is intended for use in tests.
+pub fn assert_sample<P>()
+where
+    P: Problem,
+    P::Error: Debug,
+    <P::Input as FromStr>::Err: Debug,
+{
+    let sample = P::SAMPLE.expect("Problem does not declare a sample");
+    let input = P::input_from(sample.input.trim()).expect("Failed to make input");
+    let output = P::solve(input).expect("Failed to solve");
+
+    if let Err(mismatch) = Checker::for_problem::<P>().check(&output.to_string(), sample.output) {
+        panic!("Sample solution does not match:\n{}", mismatch);
+    }
+}
+
+/// Generates datasets over a spread of seeds and sizes and panics if any of
+/// them cannot be parsed or solved.
+///
+/// This is intended for use in tests.
+pub fn assert_generates<P>()
+where
+    P: Problem,
+    P::Error: Debug,
+    <P::Input as FromStr>::Err: Debug,
+{
+    for seed in 0..10 {
+        for size in [0, 1, 2, 10, 100, 1000] {
+            let raw = generate_seeded::<P>(seed, size).expect("Problem does not generate datasets");
+            let input = P::input_from(&raw).unwrap_or_else(|e| {
+                panic!(
+                    "Failed to make input (seed {}, size {}): {:?}",
+                    seed, size, e
+                )
+            });
+
+            if let Err(e) = P::solve(input) {
+                panic!("Failed to solve (seed {}, size {}): {:?}", seed, size, e);
+            }
+        }
+    }
+}
+
+/// Generates a dataset for the given problem from a seeded RNG, so the same
+/// seed and size always produce the same dataset.
+pub fn generate_seeded<P: Problem>(seed: u64, size: usize) -> Option<String> {
+    P::generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// Loads the named input dataset, see [data] for where it is looked for.
+pub fn load_input_file(name: &str) -> Result<String, data::Error> {
+    data::INPUTS.load(name)
+}
+
+/// Loads the named expected solution, see [data] for where it is looked for.
+pub fn load_solution_file(name: &str) -> Result<String, data::Error> {
+    data::SOLUTIONS.load(name)
 }
@@ -1,30 +1,90 @@
+pub mod registry;
+
+pub use florist_plumbing::{Descriptor, Solution};
+pub use registry::{find_by_id, find_by_path, id_from_path, REGISTRY};
+
+/// Every registered solver, re-exported so that [florist_registry!] can name
+/// them from any crate.
+pub mod problems {
+    pub use calculating_expected_offspring::CalculatingExpectedOffspring;
+    pub use calculating_protein_mass::CalculatingProteinMass;
+    pub use complementing_a_strand_of_dna::ComplementingAStrandOfDna;
+    pub use computing_gc_content::ComputingGcContent;
+    pub use consensus_and_profile::ConsensusAndProfile;
+    pub use counting_dna_nucleotides::CountingDnaNucleotides;
+    pub use counting_point_mutations::CountingPointMutations;
+    pub use edit_distance::EditDistance;
+    pub use edit_distance_alignment::EditDistanceAlignment;
+    pub use enumerating_gene_orders::EnumeratingGeneOrders;
+    pub use enumerating_k_mers_lexicographically::EnumeratingKMersLexicographically;
+    pub use enumerating_oriented_gene_orderings::EnumeratingOrientedGeneOrderings;
+    pub use finding_a_motif_in_dna::FindingAMotifInDna;
+    pub use finding_a_motif_with_modifications::FindingAMotifWithModifications;
+    pub use global_alignment_with_scoring_matrix::GlobalAlignmentWithScoringMatrix;
+    pub use global_alignment_with_scoring_matrix_and_affine_gap_penalty::GlobalAlignmentWithScoringMatrixAndAffineGapPenalty;
+    pub use independent_alleles::IndependentAlleles;
+    pub use inferring_mrna_from_protein::InferringMrnaFromProtein;
+    pub use local_alignment_with_affine_gap_penalty::LocalAlignmentWithAffineGapPenalty;
+    pub use local_alignment_with_scoring_matrix::LocalAlignmentWithScoringMatrix;
+    pub use locating_restriction_sites::LocatingRestrictionSites;
+    pub use mendels_first_law::MendelsFirstLaw;
+    pub use mortal_fibonacci_rabbits::MortalFibonacciRabbits;
+    pub use multiple_alignment::MultipleAlignment;
+    pub use open_reading_frames::OpenReadingFrames;
+    pub use overlap_alignment::OverlapAlignment;
+    pub use overlap_graphs::SortedOverlapGraphs;
+    pub use rabbits_and_recurrence_relations::RabbitsAndRecurrenceRelations;
+    pub use rna_splicing::RnaSplicing;
+    pub use transcribing_dna_into_rna::TranscribingDnaIntoRna;
+    pub use transitions_and_transversions::TransitionsAndTransversions;
+    pub use translating_rna_into_protein::TranslatingRnaIntoProtein;
+}
+
+/// The dataset size used by the stress benches, which is far larger than any
+/// of the fixed input files.
+pub const STRESS_SIZE: usize = 10_000;
+
 #[macro_export]
 macro_rules! florist_bench {
-    ($name:ident, $label:literal, $file:literal, $solver:ty) => {
+    ($name:ident, $solver:ty, $desc:expr) => {
         pub fn $name(c: &mut Criterion) {
-            let mut group = c.benchmark_group($label);
+            let desc: $crate::Descriptor = $desc;
+            let mut group = c.benchmark_group(desc.title);
             group.bench_function("solution", |b| {
-                let raw = load_input_file($file).expect("Failed to load file");
+                let raw = load_input_file(desc.input_file).expect("Failed to load file");
                 b.iter(|| {
                     let input = <$solver>::input_from(&raw).expect("Failed to make input");
                     <$solver>::solve(input).expect("Failed to solve");
                 })
             });
+            if let Some(raw) = generate_seeded::<$solver>(0, $crate::STRESS_SIZE) {
+                group.bench_function("stress", |b| {
+                    b.iter(|| {
+                        let input = <$solver>::input_from(&raw).expect("Failed to make input");
+                        <$solver>::solve(input).expect("Failed to solve");
+                    })
+                });
+            }
             group.finish();
         }
     };
 }
 
+/// Generates a criterion bench group per problem, covering its input file and
+/// a generated stress dataset, plus one combined bench.
+///
+/// This is intended to be used as a [florist_registry!] callback, optionally
+/// preceded by the measurement time in seconds for the combined bench.
 #[macro_export]
 macro_rules! florist_benches {
-    ($comb_seconds:literal, $(($name:ident, $label:literal, $file:literal, $solver:ty)),+) => {
+    ($comb_seconds:literal, $(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => {
         use criterion::{criterion_group, Criterion};
         use std::time::Duration;
 
-        use florist_plumbing::{load_input_file, Problem};
+        use florist_plumbing::{generate_seeded, load_input_file, Problem};
 
         $(
-            florist_solver::florist_bench!($name, $label, $file, $solver);
+            $crate::florist_bench!($name, $solver, $desc);
         )+
 
         pub fn florist_combined(c: &mut Criterion) {
@@ -33,7 +93,8 @@ macro_rules! florist_benches {
             group.bench_function("Total runtime for all solutions, including parsing", |b| {
                 b.iter(|| {
                     $(
-                        let raw = load_input_file($file).expect("Failed to load file");
+                        let desc: $crate::Descriptor = $desc;
+                        let raw = load_input_file(desc.input_file).expect("Failed to load file");
                         let input = <$solver>::input_from(&raw).expect("Failed to make input");
                         <$solver>::solve(input).expect("Failed to solve");
                     )+
@@ -44,235 +105,91 @@ macro_rules! florist_benches {
 
         criterion_group!(benches, $($name,)+ florist_combined);
     };
-    ($(($name:ident, $label:literal, $file:literal, $solver:ty)),+) => {
-        florist_benches!{
-            10, $( ($name, $label, $file, $solver)),+
+    ($(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => {
+        $crate::florist_benches!{
+            10, $( ($name, $variant, $solver, $desc)),+
         }
     };
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
-
-    use calculating_expected_offspring::CalculatingExpectedOffspring;
-    use calculating_protein_mass::CalculatingProteinMass;
-    use complementing_a_strand_of_dna::ComplementingAStrandOfDna;
-    use computing_gc_content::ComputingGcContent;
-    use consensus_and_profile::ConsensusAndProfile;
-    use counting_dna_nucleotides::CountingDnaNucleotides;
-    use counting_point_mutations::CountingPointMutations;
-    use enumerating_gene_orders::EnumeratingGeneOrders;
-    use enumerating_k_mers_lexicographically::EnumeratingKMersLexicographically;
-    use enumerating_oriented_gene_orderings::EnumeratingOrientedGeneOrderings;
-    use finding_a_motif_in_dna::FindingAMotifInDna;
-    use florist_plumbing::{load_input_file, Problem};
-    use independent_alleles::IndependentAlleles;
-    use inferring_mrna_from_protein::InferringMrnaFromProtein;
-    use locating_restriction_sites::LocatingRestrictionSites;
-    use mendels_first_law::MendelsFirstLaw;
-    use mortal_fibonacci_rabbits::MortalFibonacciRabbits;
-    use open_reading_frames::OpenReadingFrames;
-    use overlap_graphs::SortedOverlapGraphs;
-    use rabbits_and_recurrence_relations::RabbitsAndRecurrenceRelations;
-    use rna_splicing::RnaSplicing;
-    use transcribing_dna_into_rna::TranscribingDnaIntoRna;
-    use transitions_and_transversions::TransitionsAndTransversions;
-    use translating_rna_into_protein::TranslatingRnaIntoProtein;
-
-    macro_rules! test_case {
-        ($name:ident, $file:literal, $solver:ty, $expected:literal) => {
-            #[test]
-            fn $name() {
-                let f = load_input_file($file).expect("Failed to load file");
-                let input = <$solver>::input_from(&f).expect("Failed to make input");
-                let output = <$solver>::solve(input).expect("Failed to solve");
-
-                assert_eq!(output.to_string().as_str(), $expected);
+    use florist_plumbing::{load_input_file, load_solution_file, Checker, Problem, Tolerance};
+
+    use crate::{florist_registry, Solution};
+
+    macro_rules! test_cases {
+        ($(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    let desc: $crate::Descriptor = $desc;
+                    let f = load_input_file(desc.input_file).expect("Failed to load file");
+                    let input = <$solver>::input_from(&f).expect("Failed to make input");
+                    let output = <$solver>::solve(input).expect("Failed to solve");
+
+                    let expected = match desc.solution {
+                        Solution::Inline(expected) => expected.to_string(),
+                        Solution::File(file) => {
+                            load_solution_file(file).expect("Failed to load solution file")
+                        }
+                    };
+
+                    // the tolerance is only there so the float answers don't
+                    // depend on the exact formatting of their last digit
+                    let checker =
+                        Checker::for_problem::<$solver>().with_tolerance(Tolerance::Relative(1e-12));
+                    if let Err(mismatch) = checker.check(&output.to_string(), &expected) {
+                        panic!("{}", mismatch);
+                    }
+                }
+            )+
+
+            mod samples {
+                use florist_plumbing::assert_sample;
+
+                $(
+                    #[test]
+                    fn $name() {
+                        assert_sample::<$solver>();
+                    }
+                )+
             }
-        };
-    }
 
-    fn load_solution(file: &str) -> String {
-        let p = Path::new("solutions").join(file);
-        std::fs::read_to_string(p).expect("Failed to load solution file")
-    }
+            mod json {
+                use florist_plumbing::Problem;
+
+                $(
+                    #[test]
+                    fn $name() {
+                        let sample = <$solver>::SAMPLE.expect("Problem does not declare a sample");
+                        let input = <$solver>::input_from(sample.input).expect("Failed to make input");
+                        let output = <$solver>::solve(input).expect("Failed to solve");
+                        serde_json::to_value(&output).expect("Failed to serialize");
+                    }
+                )+
+            }
 
-    macro_rules! test_case_extern_solution {
-        ($name:ident, $file:literal, $solver:ty, $solution:literal) => {
-            #[test]
-            fn $name() {
-                let f = load_input_file($file).expect("Failed to load file");
-                let input = <$solver>::input_from(&f).expect("Failed to make input");
-                let output = <$solver>::solve(input).expect("Failed to solve");
+            mod generated {
+                use florist_plumbing::assert_generates;
 
-                assert_eq!(output.to_string().as_str(), load_solution($solution).trim());
+                $(
+                    #[test]
+                    fn $name() {
+                        assert_generates::<$solver>();
+                    }
+                )+
             }
         };
     }
 
-    test_case!(
-        counting_dna_nucleotides,
-        "rosalind_dna.txt",
-        CountingDnaNucleotides,
-        "205 211 214 209"
-    );
-
-    test_case!(
-        transcribing_dna_into_rna,
-        "rosalind_rna.txt",
-        TranscribingDnaIntoRna,
-        "CUAAGCAGCAAUGCUGCACCGCGUGAAGAGCAGUGUGGUAGUCCACUUCCGUGACCCAUAAUUAGAGGUGCUGCUUCACUAGCGUGUGCACCUUACAUCCCACGGACGGGUGAGGGCUACUCUGCAUAAUUUGUCAUGCAUCCUUACAUUGUUUCAGAUUGACACUAAUAAGGGCACCAACUGCCAGGCAUCGAAGAAGGCAAUACUGGCUCAGGAACGGGGUAGAGCUUGUGGAUGCGAUCAUACAAAAGUCUUAAGCCUAUCUUCAAGCCUCUUUCUUGAUGCGGAAGCGAAAGCCACUUUCGUGUCCAGCGAGCGACGAUGCUAUUAGAACCUUAGGUUGUAUUACGCAGAACUCCGAGAAUUAUGGAACCAGGUGCCUUUAGGGAUUCAGACAGGCGAGACCCGGAGUCGAACUUUCACUACGACGCUAGCGGGAGGUUGGAGCUCUCGACCAUAUAGAAGAAGAGUAGUCCCAGGGGUAAGCGGCCCGAUAAAAACUUGACUCCUGGUGCUAUGCUCGUAGUAGGACAACAAGGCCGAUGCUUGUUGUGACCCAAGAAAUCUGUCGUGAGGAUACACUGGGCGUACUUAAAAAGAAGUCUACUUCAGUUGGAGCGCCUGAGGCGGACCUACUAAGGACCUUAGGGGAUGACGUUGCAAUACACAUUUCCUCCAACGAUCACAGUUGGUGGACCAUAGCUCAAACCUGAGCGAGCUGGUAUCUAGGACCCUGACUGCACGUCUGCUGCUGAAUAUGAUCUCUACAACCGGGUGAACAGAGCUACCCUGCAUGGCCCGUGAAGAAUCGCUUGCGGCUUAAGUCAUCAAUCCCAGGAGUUUAGAACGAAGACGCGAGGUCCAGGGACCGUCCCCGCUUGAGGUUGUUGAGGACAAGCGUCGGGCAAGCCGUCGAUCUUGGAAGAGAGUG"
-    );
-
-    test_case!(
-        complementing_a_strand_of_dna,
-        "rosalind_revc.txt",
-        ComplementingAStrandOfDna,
-        "CAGGTGGCTGGAGACGAGGATTCTGAATTCCCAGGGGTGACAAGCACTCGCACTCCTATCTACCATCGAAATGAGCCGGGCTAGCTTCTTGTTTCAGAGCCGGTTACATACTACTCCATGGTACCTCTGGACGAATCGCCGTACCCCAAGTAGTTAGTTCGGGCCAGTCTACGGGTACGAACAATGCATCGTTTATCAATTTAAAGAGTCGGCAAGCCTCACCCAGTTCAATGGGGAAATTGTGACAGCGATCCCCACCGAGATCATACTGTGCGTACTTTAAGACTCTGAAGGAAAGAATGGTACACCAGTCATTTTATAGAAGCGAGTAACCACAGGGAGTACGAAACACGCTACTTTTCCAACGTCAGTGGACGATCATGGCGCGAGAAACCAGGTAGATGTAGCCTATACCAAACTGTTCTTGGTGTGTGAGCTCGCTGGGCAGGATCACGCCCGGAGTCGCCTGTCCACCCAGTAGACCGGGGGCTGTGTTGCCCCGCGCTCCAGGAGTGAACGACTAAATTTAGTGAATCTCATGCATGGCTAGCCGCGTGCCCCCGATTTTCTATAAGGGACGATTGAGGTAAGTTTCGTTGACTTGAGTAGTTCGACCTCATATAATGAGATTACCTGTTCGCACAGCAATCTTGTGAGCAGAGGAACCCCTACCTGGCAGAAACTAGCAAGATCGCAACTACTATAACTATGAGATCGGTAAGAAAAACTGTTAATCGGGGATGGCCGACGTTGTACCGGAACATCATCCGATAGCCTAAGGTTGATGCCAGCCATCCGGGCTGCGAACGGTATATGAGTACACACTCATTAGCGTTACTCCCCCCAAGATCCCGCCACCGGAACGCCCGCCCGGACACAAGGTCCTCGGTAGTGGGGACAATCCCGTGGAGCCTTTGATAGCGTAAGTACGAGCAGTGATATCTTTGCCTAAAAG"
-    );
-
-    test_case!(
-        rabbits_and_recurrence_relations,
-        "rosalind_fib.txt",
-        RabbitsAndRecurrenceRelations,
-        "574888488199"
-    );
-
-    test_case!(
-        computing_gc_content,
-        "rosalind_gc.txt",
-        ComputingGcContent,
-        "Rosalind_9913\n51.2396694214876"
-    );
-
-    test_case!(
-        counting_point_mutations,
-        "rosalind_hamm.txt",
-        CountingPointMutations,
-        "491"
-    );
+    florist_registry!(test_cases);
 
-    test_case_extern_solution!(
-        enumerating_gene_orders,
-        "rosalind_perm.txt",
-        EnumeratingGeneOrders,
-        "enumerating_gene_orders.txt"
-    );
-
-    test_case_extern_solution!(
-        enumerating_k_mers_lexicographically,
-        "rosalind_lexf.txt",
-        EnumeratingKMersLexicographically,
-        "enumerating_k_mers_lexicographically.txt"
-    );
-
-    test_case!(
-        mendels_first_law,
-        "rosalind_iprb.txt",
-        MendelsFirstLaw,
-        "0.7584388185654007"
-    );
-
-    test_case_extern_solution!(
-        translating_rna_into_protein,
-        "rosalind_prot.txt",
-        TranslatingRnaIntoProtein,
-        "translating_rna_into_protein.txt"
-    );
-
-    test_case!(
-        calculating_protein_mass,
-        "rosalind_prtm.txt",
-        CalculatingProteinMass,
-        "117772.67882000057"
-    );
-
-    test_case!(
-        finding_a_motif_in_dna,
-        "rosalind_subs.txt",
-        FindingAMotifInDna,
-        "89 104 111 140 176 227 255 262 269 301 359 370 397 428 456 475 510 601 608 637 644 651 658 694 747 804"
-    );
-
-    test_case!(
-        calculating_expected_offspring,
-        "rosalind_iev.txt",
-        CalculatingExpectedOffspring,
-        "147988"
-    );
-
-    test_case_extern_solution!(
-        locating_restriction_sites,
-        "rosalind_revp.txt",
-        LocatingRestrictionSites,
-        "locating_restriction_sites.txt"
-    );
-
-    // Man, the authors didn't even mention that they could give inputs that
-    // would overflow 64 bit unsigned integers. That's actually pretty awful,
-    // considering the problem came out almost a decade ago. This would explain
-    // why I got this "wrong" several times.
-    test_case!(
-        mortal_fibonacci_rabbits,
-        "rosalind_fibd.txt",
-        MortalFibonacciRabbits,
-        "258314806822396236"
-    );
-
-    test_case!(
-        inferring_mrna_from_protein,
-        "rosalind_mrna.txt",
-        InferringMrnaFromProtein,
-        "549056"
-    );
-
-    test_case!(
-        independent_alleles,
-        "rosalind_lia.txt",
-        IndependentAlleles,
-        "0.30060220579105046"
-    );
-
-    test_case_extern_solution!(
-        overlap_graphs,
-        "rosalind_grph.txt",
-        SortedOverlapGraphs,
-        "overlap_graphs.txt"
-    );
-
-    test_case_extern_solution!(
-        consensus_and_profile,
-        "rosalind_cons.txt",
-        ConsensusAndProfile,
-        "consensus_and_profile.txt"
-    );
-
-    test_case_extern_solution!(
-        open_reading_frames,
-        "rosalind_orf.txt",
-        OpenReadingFrames,
-        "open_reading_frames.txt"
-    );
-
-    test_case!(
-        rna_splicing,
-        "rosalind_splc.txt",
-        RnaSplicing,
-        "MRAHASYATLLSYTALRRTFMLMKCYFGGSNRRRPREDRSFYTRPIGMPEQSRKLDGTGSGRCLSYYRKCDSPPSSPQNVEINHRGSVHIIVVSLGEEIQVSTEPDSVPKRNTDTKRPYAHECSLPEKPGWSYELEVLYEPSYFLGSTSKGLFIREATVPAQIGGLPKQHCKAVGIGRLTHAITQGESTFP"
-    );
-
-    test_case!(
-        transitions_and_transversions,
-        "rosalind_tran.txt",
-        TransitionsAndTransversions,
-        "1.989010989010989"
-    );
-
-    test_case_extern_solution!(
-        enumerating_oriented_gene_orderings,
-        "rosalind_sign.txt",
-        EnumeratingOrientedGeneOrderings,
-        "enumerating_oriented_gene_orderings.txt"
-    );
+    #[test]
+    fn registry_ids_are_unique() {
+        let mut ids: Vec<_> = crate::REGISTRY.iter().map(|d| d.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), crate::REGISTRY.len());
+    }
 }
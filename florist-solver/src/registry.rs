@@ -0,0 +1,516 @@
+//! The single list of every problem this workspace knows how to solve.
+//!
+//! The cli subcommands, the regression tests and the benches are all generated
+//! from [florist_registry!], so adding a problem is a matter of writing its
+//! crate and adding one entry below.
+use std::path::Path;
+
+use florist_plumbing::Descriptor;
+
+/// Invokes the given callback macro with one entry per registered problem.
+///
+/// Each entry is a tuple of `(snake_name, VariantName, SolverType,
+/// Descriptor)`, where `snake_name` is suitable for function names,
+/// `VariantName` is suitable for enum variants, and the solver type implements
+/// [florist_plumbing::Problem].
+///
+/// Problems that translate codons end their entry with `genetic_code`, and
+/// their solver has an inherent `solve_with_code(input, &GeneticCode)` to
+/// solve with a genetic code other than the standard one. Callbacks that
+/// don't care can ignore it.
+///
+/// ```ignore
+/// macro_rules! my_callback {
+///     ($(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => { ... };
+/// }
+///
+/// florist_solver::florist_registry!(my_callback);
+/// ```
+#[macro_export]
+macro_rules! florist_registry {
+    ($($callback:ident)::+) => {
+        $($callback)::+! {
+            (
+                counting_dna_nucleotides,
+                CountingDnaNucleotides,
+                $crate::problems::CountingDnaNucleotides,
+                $crate::Descriptor {
+                    id: "DNA",
+                    title: "Counting DNA Nucleotides",
+                    slug: "counting-dna-nucleotides",
+                    input_file: "rosalind_dna.txt",
+                    solution: $crate::Solution::Inline("205 211 214 209"),
+                }
+            ),
+            (
+                transcribing_dna_into_rna,
+                TranscribingDnaIntoRna,
+                $crate::problems::TranscribingDnaIntoRna,
+                $crate::Descriptor {
+                    id: "RNA",
+                    title: "Transcribing DNA into RNA",
+                    slug: "transcribing-dna-into-rna",
+                    input_file: "rosalind_rna.txt",
+                    solution: $crate::Solution::Inline(
+                        "CUAAGCAGCAAUGCUGCACCGCGUGAAGAGCAGUGUGGUAGUCCACUUCCGUGACCCAUAAUUAGAGGUGCUGCUUCACUAGCGUGUGCACCUUACAUCCCACGGACGGGUGAGGGCUACUCUGCAUAAUUUGUCAUGCAUCCUUACAUUGUUUCAGAUUGACACUAAUAAGGGCACCAACUGCCAGGCAUCGAAGAAGGCAAUACUGGCUCAGGAACGGGGUAGAGCUUGUGGAUGCGAUCAUACAAAAGUCUUAAGCCUAUCUUCAAGCCUCUUUCUUGAUGCGGAAGCGAAAGCCACUUUCGUGUCCAGCGAGCGACGAUGCUAUUAGAACCUUAGGUUGUAUUACGCAGAACUCCGAGAAUUAUGGAACCAGGUGCCUUUAGGGAUUCAGACAGGCGAGACCCGGAGUCGAACUUUCACUACGACGCUAGCGGGAGGUUGGAGCUCUCGACCAUAUAGAAGAAGAGUAGUCCCAGGGGUAAGCGGCCCGAUAAAAACUUGACUCCUGGUGCUAUGCUCGUAGUAGGACAACAAGGCCGAUGCUUGUUGUGACCCAAGAAAUCUGUCGUGAGGAUACACUGGGCGUACUUAAAAAGAAGUCUACUUCAGUUGGAGCGCCUGAGGCGGACCUACUAAGGACCUUAGGGGAUGACGUUGCAAUACACAUUUCCUCCAACGAUCACAGUUGGUGGACCAUAGCUCAAACCUGAGCGAGCUGGUAUCUAGGACCCUGACUGCACGUCUGCUGCUGAAUAUGAUCUCUACAACCGGGUGAACAGAGCUACCCUGCAUGGCCCGUGAAGAAUCGCUUGCGGCUUAAGUCAUCAAUCCCAGGAGUUUAGAACGAAGACGCGAGGUCCAGGGACCGUCCCCGCUUGAGGUUGUUGAGGACAAGCGUCGGGCAAGCCGUCGAUCUUGGAAGAGAGUG"
+                    ),
+                }
+            ),
+            (
+                complementing_a_strand_of_dna,
+                ComplementingAStrandOfDna,
+                $crate::problems::ComplementingAStrandOfDna,
+                $crate::Descriptor {
+                    id: "REVC",
+                    title: "Complementing a Strand of DNA",
+                    slug: "complementing-a-strand-of-dna",
+                    input_file: "rosalind_revc.txt",
+                    solution: $crate::Solution::Inline(
+                        "CAGGTGGCTGGAGACGAGGATTCTGAATTCCCAGGGGTGACAAGCACTCGCACTCCTATCTACCATCGAAATGAGCCGGGCTAGCTTCTTGTTTCAGAGCCGGTTACATACTACTCCATGGTACCTCTGGACGAATCGCCGTACCCCAAGTAGTTAGTTCGGGCCAGTCTACGGGTACGAACAATGCATCGTTTATCAATTTAAAGAGTCGGCAAGCCTCACCCAGTTCAATGGGGAAATTGTGACAGCGATCCCCACCGAGATCATACTGTGCGTACTTTAAGACTCTGAAGGAAAGAATGGTACACCAGTCATTTTATAGAAGCGAGTAACCACAGGGAGTACGAAACACGCTACTTTTCCAACGTCAGTGGACGATCATGGCGCGAGAAACCAGGTAGATGTAGCCTATACCAAACTGTTCTTGGTGTGTGAGCTCGCTGGGCAGGATCACGCCCGGAGTCGCCTGTCCACCCAGTAGACCGGGGGCTGTGTTGCCCCGCGCTCCAGGAGTGAACGACTAAATTTAGTGAATCTCATGCATGGCTAGCCGCGTGCCCCCGATTTTCTATAAGGGACGATTGAGGTAAGTTTCGTTGACTTGAGTAGTTCGACCTCATATAATGAGATTACCTGTTCGCACAGCAATCTTGTGAGCAGAGGAACCCCTACCTGGCAGAAACTAGCAAGATCGCAACTACTATAACTATGAGATCGGTAAGAAAAACTGTTAATCGGGGATGGCCGACGTTGTACCGGAACATCATCCGATAGCCTAAGGTTGATGCCAGCCATCCGGGCTGCGAACGGTATATGAGTACACACTCATTAGCGTTACTCCCCCCAAGATCCCGCCACCGGAACGCCCGCCCGGACACAAGGTCCTCGGTAGTGGGGACAATCCCGTGGAGCCTTTGATAGCGTAAGTACGAGCAGTGATATCTTTGCCTAAAAG"
+                    ),
+                }
+            ),
+            (
+                rabbits_and_recurrence_relations,
+                RabbitsAndRecurrenceRelations,
+                $crate::problems::RabbitsAndRecurrenceRelations,
+                $crate::Descriptor {
+                    id: "FIB",
+                    title: "Rabbits and Recurrence Relations",
+                    slug: "rabbits-and-recurrence-relations",
+                    input_file: "rosalind_fib.txt",
+                    solution: $crate::Solution::Inline("574888488199"),
+                }
+            ),
+            (
+                computing_gc_content,
+                ComputingGcContent,
+                $crate::problems::ComputingGcContent,
+                $crate::Descriptor {
+                    id: "GC",
+                    title: "Computing GC Content",
+                    slug: "computing-gc-content",
+                    input_file: "rosalind_gc.txt",
+                    solution: $crate::Solution::Inline("Rosalind_9913\n51.2396694214876"),
+                }
+            ),
+            (
+                counting_point_mutations,
+                CountingPointMutations,
+                $crate::problems::CountingPointMutations,
+                $crate::Descriptor {
+                    id: "HAMM",
+                    title: "Counting Point Mutations",
+                    slug: "counting-point-mutations",
+                    input_file: "rosalind_hamm.txt",
+                    solution: $crate::Solution::Inline("491"),
+                }
+            ),
+            (
+                enumerating_gene_orders,
+                EnumeratingGeneOrders,
+                $crate::problems::EnumeratingGeneOrders,
+                $crate::Descriptor {
+                    id: "PERM",
+                    title: "Enumerating Gene Orders",
+                    slug: "enumerating-gene-orders",
+                    input_file: "rosalind_perm.txt",
+                    solution: $crate::Solution::File("enumerating_gene_orders.txt"),
+                }
+            ),
+            (
+                enumerating_k_mers_lexicographically,
+                EnumeratingKMersLexicographically,
+                $crate::problems::EnumeratingKMersLexicographically,
+                $crate::Descriptor {
+                    id: "LEXF",
+                    title: "Enumerating k-mers Lexicographically",
+                    slug: "enumerating-k-mers-lexicographically",
+                    input_file: "rosalind_lexf.txt",
+                    solution: $crate::Solution::File("enumerating_k_mers_lexicographically.txt"),
+                }
+            ),
+            (
+                mendels_first_law,
+                MendelsFirstLaw,
+                $crate::problems::MendelsFirstLaw,
+                $crate::Descriptor {
+                    id: "IPRB",
+                    title: "Mendel's First Law",
+                    slug: "mendels-first-law",
+                    input_file: "rosalind_iprb.txt",
+                    solution: $crate::Solution::Inline("0.7584388185654007"),
+                }
+            ),
+            (
+                translating_rna_into_protein,
+                TranslatingRnaIntoProtein,
+                $crate::problems::TranslatingRnaIntoProtein,
+                $crate::Descriptor {
+                    id: "PROT",
+                    title: "Translating RNA into Protein",
+                    slug: "translating-rna-into-protein",
+                    input_file: "rosalind_prot.txt",
+                    solution: $crate::Solution::File("translating_rna_into_protein.txt"),
+                },
+                genetic_code
+            ),
+            (
+                calculating_protein_mass,
+                CalculatingProteinMass,
+                $crate::problems::CalculatingProteinMass,
+                $crate::Descriptor {
+                    id: "PRTM",
+                    title: "Calculating Protein Mass",
+                    slug: "calculating-protein-mass",
+                    input_file: "rosalind_prtm.txt",
+                    solution: $crate::Solution::Inline("117772.67882000057"),
+                }
+            ),
+            (
+                finding_a_motif_in_dna,
+                FindingAMotifInDna,
+                $crate::problems::FindingAMotifInDna,
+                $crate::Descriptor {
+                    id: "SUBS",
+                    title: "Finding a Motif in DNA",
+                    slug: "finding-a-motif-in-dna",
+                    input_file: "rosalind_subs.txt",
+                    solution: $crate::Solution::Inline(
+                        "89 104 111 140 176 227 255 262 269 301 359 370 397 428 456 475 510 601 608 637 644 651 658 694 747 804"
+                    ),
+                }
+            ),
+            (
+                calculating_expected_offspring,
+                CalculatingExpectedOffspring,
+                $crate::problems::CalculatingExpectedOffspring,
+                $crate::Descriptor {
+                    id: "IEV",
+                    title: "Calculating Expected Offspring",
+                    slug: "calculating-expected-offspring",
+                    input_file: "rosalind_iev.txt",
+                    solution: $crate::Solution::Inline("147988"),
+                }
+            ),
+            (
+                locating_restriction_sites,
+                LocatingRestrictionSites,
+                $crate::problems::LocatingRestrictionSites,
+                $crate::Descriptor {
+                    id: "REVP",
+                    title: "Locating Restriction Sites",
+                    slug: "locating-restriction-sites",
+                    input_file: "rosalind_revp.txt",
+                    solution: $crate::Solution::File("locating_restriction_sites.txt"),
+                }
+            ),
+            // Man, the authors didn't even mention that they could give inputs
+            // that would overflow 64 bit unsigned integers. That's actually
+            // pretty awful, considering the problem came out almost a decade
+            // ago. This would explain why I got this "wrong" several times.
+            (
+                mortal_fibonacci_rabbits,
+                MortalFibonacciRabbits,
+                $crate::problems::MortalFibonacciRabbits,
+                $crate::Descriptor {
+                    id: "FIBD",
+                    title: "Mortal Fibonacci Rabbits",
+                    slug: "mortal-fibonacci-rabbits",
+                    input_file: "rosalind_fibd.txt",
+                    solution: $crate::Solution::Inline("258314806822396236"),
+                }
+            ),
+            (
+                inferring_mrna_from_protein,
+                InferringMrnaFromProtein,
+                $crate::problems::InferringMrnaFromProtein,
+                $crate::Descriptor {
+                    id: "MRNA",
+                    title: "Inferring mRNA from Protein",
+                    slug: "inferring-mrna-from-protein",
+                    input_file: "rosalind_mrna.txt",
+                    solution: $crate::Solution::Inline("549056"),
+                }
+            ),
+            (
+                independent_alleles,
+                IndependentAlleles,
+                $crate::problems::IndependentAlleles,
+                $crate::Descriptor {
+                    id: "LIA",
+                    title: "Independent Alleles",
+                    slug: "independent-alleles",
+                    input_file: "rosalind_lia.txt",
+                    solution: $crate::Solution::Inline("0.30060220579105046"),
+                }
+            ),
+            (
+                overlap_graphs,
+                OverlapGraphs,
+                $crate::problems::SortedOverlapGraphs,
+                $crate::Descriptor {
+                    id: "GRPH",
+                    title: "Overlap Graphs",
+                    slug: "overlap-graphs",
+                    input_file: "rosalind_grph.txt",
+                    solution: $crate::Solution::File("overlap_graphs.txt"),
+                }
+            ),
+            (
+                consensus_and_profile,
+                ConsensusAndProfile,
+                $crate::problems::ConsensusAndProfile,
+                $crate::Descriptor {
+                    id: "CONS",
+                    title: "Consensus and Profile",
+                    slug: "consensus-and-profile",
+                    input_file: "rosalind_cons.txt",
+                    solution: $crate::Solution::File("consensus_and_profile.txt"),
+                }
+            ),
+            (
+                open_reading_frames,
+                OpenReadingFrames,
+                $crate::problems::OpenReadingFrames,
+                $crate::Descriptor {
+                    id: "ORF",
+                    title: "Open Reading Frames",
+                    slug: "open-reading-frames",
+                    input_file: "rosalind_orf.txt",
+                    solution: $crate::Solution::File("open_reading_frames.txt"),
+                },
+                genetic_code
+            ),
+            (
+                rna_splicing,
+                RnaSplicing,
+                $crate::problems::RnaSplicing,
+                $crate::Descriptor {
+                    id: "SPLC",
+                    title: "RNA Splicing",
+                    slug: "rna-splicing",
+                    input_file: "rosalind_splc.txt",
+                    solution: $crate::Solution::Inline(
+                        "MRAHASYATLLSYTALRRTFMLMKCYFGGSNRRRPREDRSFYTRPIGMPEQSRKLDGTGSGRCLSYYRKCDSPPSSPQNVEINHRGSVHIIVVSLGEEIQVSTEPDSVPKRNTDTKRPYAHECSLPEKPGWSYELEVLYEPSYFLGSTSKGLFIREATVPAQIGGLPKQHCKAVGIGRLTHAITQGESTFP"
+                    ),
+                }
+            ),
+            (
+                transitions_and_transversions,
+                TransitionsAndTransversions,
+                $crate::problems::TransitionsAndTransversions,
+                $crate::Descriptor {
+                    id: "TRAN",
+                    title: "Transitions and Transversions",
+                    slug: "transitions-and-transversions",
+                    input_file: "rosalind_tran.txt",
+                    solution: $crate::Solution::Inline("1.989010989010989"),
+                }
+            ),
+            (
+                enumerating_oriented_gene_orderings,
+                EnumeratingOrientedGeneOrderings,
+                $crate::problems::EnumeratingOrientedGeneOrderings,
+                $crate::Descriptor {
+                    id: "SIGN",
+                    title: "Enumerating Oriented Gene Orderings",
+                    slug: "enumerating-oriented-gene-orderings",
+                    input_file: "rosalind_sign.txt",
+                    solution: $crate::Solution::File("enumerating_oriented_gene_orderings.txt"),
+                }
+            ),
+            (
+                edit_distance,
+                EditDistance,
+                $crate::problems::EditDistance,
+                $crate::Descriptor {
+                    id: "EDIT",
+                    title: "Edit Distance",
+                    slug: "edit-distance",
+                    input_file: "rosalind_edit.txt",
+                    solution: $crate::Solution::File("edit_distance.txt"),
+                }
+            ),
+            (
+                edit_distance_alignment,
+                EditDistanceAlignment,
+                $crate::problems::EditDistanceAlignment,
+                $crate::Descriptor {
+                    id: "EDTA",
+                    title: "Edit Distance Alignment",
+                    slug: "edit-distance-alignment",
+                    input_file: "rosalind_edta.txt",
+                    solution: $crate::Solution::File("edit_distance_alignment.txt"),
+                }
+            ),
+            (
+                global_alignment_with_scoring_matrix,
+                GlobalAlignmentWithScoringMatrix,
+                $crate::problems::GlobalAlignmentWithScoringMatrix,
+                $crate::Descriptor {
+                    id: "GLOB",
+                    title: "Global Alignment with Scoring Matrix",
+                    slug: "global-alignment-with-scoring-matrix",
+                    input_file: "rosalind_glob.txt",
+                    solution: $crate::Solution::File("global_alignment_with_scoring_matrix.txt"),
+                }
+            ),
+            (
+                local_alignment_with_scoring_matrix,
+                LocalAlignmentWithScoringMatrix,
+                $crate::problems::LocalAlignmentWithScoringMatrix,
+                $crate::Descriptor {
+                    id: "LOCA",
+                    title: "Local Alignment with Scoring Matrix",
+                    slug: "local-alignment-with-scoring-matrix",
+                    input_file: "rosalind_loca.txt",
+                    solution: $crate::Solution::File("local_alignment_with_scoring_matrix.txt"),
+                }
+            ),
+            (
+                global_alignment_with_scoring_matrix_and_affine_gap_penalty,
+                GlobalAlignmentWithScoringMatrixAndAffineGapPenalty,
+                $crate::problems::GlobalAlignmentWithScoringMatrixAndAffineGapPenalty,
+                $crate::Descriptor {
+                    id: "GAFF",
+                    title: "Global Alignment with Scoring Matrix and Affine Gap Penalty",
+                    slug: "global-alignment-with-scoring-matrix-and-affine-gap-penalty",
+                    input_file: "rosalind_gaff.txt",
+                    solution: $crate::Solution::File("global_alignment_with_scoring_matrix_and_affine_gap_penalty.txt"),
+                }
+            ),
+            (
+                local_alignment_with_affine_gap_penalty,
+                LocalAlignmentWithAffineGapPenalty,
+                $crate::problems::LocalAlignmentWithAffineGapPenalty,
+                $crate::Descriptor {
+                    id: "LAFF",
+                    title: "Local Alignment with Affine Gap Penalty",
+                    slug: "local-alignment-with-affine-gap-penalty",
+                    input_file: "rosalind_laff.txt",
+                    solution: $crate::Solution::File("local_alignment_with_affine_gap_penalty.txt"),
+                }
+            ),
+            (
+                finding_a_motif_with_modifications,
+                FindingAMotifWithModifications,
+                $crate::problems::FindingAMotifWithModifications,
+                $crate::Descriptor {
+                    id: "SIMS",
+                    title: "Finding a Motif with Modifications",
+                    slug: "finding-a-motif-with-modifications",
+                    input_file: "rosalind_sims.txt",
+                    solution: $crate::Solution::File("finding_a_motif_with_modifications.txt"),
+                }
+            ),
+            (
+                overlap_alignment,
+                OverlapAlignment,
+                $crate::problems::OverlapAlignment,
+                $crate::Descriptor {
+                    id: "OAP",
+                    title: "Overlap Alignment",
+                    slug: "overlap-alignment",
+                    input_file: "rosalind_oap.txt",
+                    solution: $crate::Solution::File("overlap_alignment.txt"),
+                }
+            ),
+            (
+                multiple_alignment,
+                MultipleAlignment,
+                $crate::problems::MultipleAlignment,
+                $crate::Descriptor {
+                    id: "MULT",
+                    title: "Multiple Alignment",
+                    slug: "multiple-alignment",
+                    input_file: "rosalind_mult.txt",
+                    solution: $crate::Solution::File("multiple_alignment.txt"),
+                }
+            )
+        }
+    };
+}
+
+macro_rules! registry_descriptors {
+    ($(($name:ident, $variant:ident, $solver:ty, $desc:expr $(, $marker:ident)?)),+ $(,)?) => {
+        /// The descriptors of every registered problem, in registry order.
+        pub static REGISTRY: &[Descriptor] = &[$($desc),+];
+    };
+}
+
+florist_registry!(registry_descriptors);
+
+/// Finds the registered problem with the given Rosalind ID, ignoring case.
+pub fn find_by_id(id: &str) -> Option<&'static Descriptor> {
+    REGISTRY.iter().find(|d| d.id.eq_ignore_ascii_case(id))
+}
+
+/// Extracts the Rosalind problem ID from a dataset name like
+/// `rosalind_gc.txt`.
+///
+/// Browsers tend to rename repeated downloads to things like
+/// `rosalind_gc(1).txt` or `rosalind_gc_2.txt`, so anything after the ID is
+/// ignored.
+pub fn id_from_path(path: &Path) -> Option<String> {
+    let stem = path.file_stem()?.to_str()?;
+    let rest = stem.strip_prefix("rosalind_")?;
+    let id: String = rest
+        .chars()
+        .take_while(|ch| ch.is_ascii_alphabetic())
+        .collect();
+
+    if id.is_empty() {
+        None
+    } else {
+        Some(id.to_ascii_uppercase())
+    }
+}
+
+/// Finds the registered problem for a dataset named like `rosalind_<id>.txt`.
+pub fn find_by_path(path: &Path) -> Option<&'static Descriptor> {
+    find_by_id(&id_from_path(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finding_by_id() {
+        assert_eq!(
+            find_by_id("GC").map(|d| d.slug),
+            Some("computing-gc-content")
+        );
+        assert_eq!(
+            find_by_id("gc").map(|d| d.slug),
+            Some("computing-gc-content")
+        );
+        assert!(find_by_id("NOPE").is_none());
+    }
+
+    #[test]
+    fn extracting_ids() {
+        assert_eq!(
+            id_from_path(Path::new("inputs/rosalind_gc.txt")),
+            Some("GC".to_string())
+        );
+        assert_eq!(
+            id_from_path(Path::new("rosalind_revc(1).txt")),
+            Some("REVC".to_string())
+        );
+        assert_eq!(
+            id_from_path(Path::new("rosalind_fibd_2.txt")),
+            Some("FIBD".to_string())
+        );
+        assert_eq!(id_from_path(Path::new("gc.txt")), None);
+        assert_eq!(id_from_path(Path::new("rosalind_.txt")), None);
+    }
+
+    #[test]
+    fn finding_by_path() {
+        let desc = find_by_path(Path::new("/tmp/rosalind_cons.txt")).unwrap();
+        assert_eq!(desc.title, "Consensus and Profile");
+        assert!(find_by_path(Path::new("/tmp/rosalind_zzz.txt")).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::{Aligner, GapPenalty, Mode};
+use florist_core::{ProteinSequence, ScoringMatrix};
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The score of the best global alignment and the alignment itself.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct GlobalAlignment {
+    pub score: i32,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for GlobalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}\n{}", self.score, self.first, self.second)
+    }
+}
+
+pub struct GlobalAlignmentWithScoringMatrixAndAffineGapPenalty;
+
+impl GlobalAlignmentWithScoringMatrixAndAffineGapPenalty {
+    /// BLOSUM62, opening gaps for 11 and extending them for 1.
+    fn aligner() -> Aligner<&'static ScoringMatrix> {
+        Aligner::new(
+            Mode::Global,
+            ScoringMatrix::blosum62(),
+            GapPenalty::affine(11, 1),
+        )
+    }
+}
+
+impl Problem for GlobalAlignmentWithScoringMatrixAndAffineGapPenalty {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = GlobalAlignment;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_79
+PRTEINS
+>Rosalind_41
+PRTWPSEIN",
+        output: "8
+PRT---EINS
+PRTWPSEIN-",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: ProteinSequence = input[0].1.parse()?;
+        let second: ProteinSequence = input[1].1.parse()?;
+        let alignment = Self::aligner().align(&first, &second);
+
+        Ok(GlobalAlignment {
+            score: alignment.score,
+            first: alignment.first,
+            second: alignment.second,
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: ProteinSequence = florist_gen::sequence(rng, size.min(100));
+        let rate = rng.gen_range(0.0..0.5);
+        let second = florist_gen::evolve(rng, &first, rate);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::GAP;
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<GlobalAlignmentWithScoringMatrixAndAffineGapPenalty>();
+    }
+
+    #[test]
+    fn rows_score_as_reported() {
+        let sample = GlobalAlignmentWithScoringMatrixAndAffineGapPenalty::SAMPLE.unwrap();
+        let input =
+            GlobalAlignmentWithScoringMatrixAndAffineGapPenalty::input_from(sample.input).unwrap();
+        let output =
+            GlobalAlignmentWithScoringMatrixAndAffineGapPenalty::solve(input.clone()).unwrap();
+
+        // Rosalind accepts any alignment as good
+        assert_eq!(output.first.replace(GAP, ""), input[0].1);
+        assert_eq!(output.second.replace(GAP, ""), input[1].1);
+        let aligner = GlobalAlignmentWithScoringMatrixAndAffineGapPenalty::aligner();
+        assert_eq!(
+            aligner.score_rows(&output.first, &output.second),
+            output.score
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::bail;
+use florist_align::{Aligner, GapPenalty, Mode};
+use florist_core::{ProteinSequence, ScoringMatrix};
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
+
+pub struct GlobalAlignmentWithScoringMatrix;
+
+impl Problem for GlobalAlignmentWithScoringMatrix {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = i32;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_67
+PLEASANTLY
+>Rosalind_17
+MEANLY",
+        output: "8",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: ProteinSequence = input[0].1.parse()?;
+        let second: ProteinSequence = input[1].1.parse()?;
+        let aligner = Aligner::new(
+            Mode::Global,
+            ScoringMatrix::blosum62(),
+            GapPenalty::linear(5),
+        );
+
+        Ok(aligner.align(&first, &second).score)
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: ProteinSequence = florist_gen::sequence(rng, size.min(1000));
+        let rate = rng.gen_range(0.0..0.5);
+        let second = florist_gen::evolve(rng, &first, rate);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<GlobalAlignmentWithScoringMatrix>();
+    }
+}
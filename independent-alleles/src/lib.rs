@@ -1,6 +1,7 @@
 use anyhow::bail;
 use florist_inputs::U32List;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct IndependentAlleles;
 
@@ -9,6 +10,11 @@ impl Problem for IndependentAlleles {
     type Input = U32List;
     type Output = f64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "2 1",
+        output: "0.684",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         if input.len() != 2 {
             bail!("Invalid input, wrong number of values: {}", input);
@@ -37,6 +43,12 @@ impl Problem for IndependentAlleles {
                 .sum()
         })
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let generations = size.clamp(1, 7) as u32;
+        let n = rng.gen_range(1..=2_u32.pow(generations));
+        Some(format!("{} {}", generations, n))
+    }
 }
 
 fn binomial(k: u32, n: u32, p: f64) -> f64 {
@@ -49,13 +61,12 @@ fn binomial_coeff(k: u32, n: u32) -> f64 {
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
-    use std::str::FromStr;
 
     #[test]
-    fn expected_genotypes() {
-        let input = U32List::from_str("2 1").unwrap();
-        let output = IndependentAlleles::solve(input).unwrap();
-        assert!((output - 0.68359375).abs() < f64::EPSILON);
+    fn sample() {
+        assert_sample::<IndependentAlleles>();
     }
 }
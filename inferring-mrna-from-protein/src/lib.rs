@@ -1,5 +1,6 @@
-use florist_core::{AminoAcid, Error, ProteinSequence};
-use florist_plumbing::Problem;
+use florist_core::{AminoAcid, Error, Protein, ProteinSequence};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct InferringMrnaFromProtein;
 
@@ -8,6 +9,11 @@ impl Problem for InferringMrnaFromProtein {
     type Input = ProteinSequence;
     type Output = u64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "MA",
+        output: "12",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut working = 1_u64;
         let mut running = 1_u64;
@@ -25,18 +31,20 @@ impl Problem for InferringMrnaFromProtein {
 
         Ok((running * working % 1_000_000 * 3) % 1_000_000)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        Some(florist_gen::sequence::<Protein, _>(rng, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn mod_count() {
-        let input = ProteinSequence::from_str("MA").unwrap();
-        let output = InferringMrnaFromProtein::solve(input).unwrap();
-        assert_eq!(output, 12);
+    fn sample() {
+        assert_sample::<InferringMrnaFromProtein>();
     }
 }
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::{Aligner, GapPenalty, Mode};
+use florist_core::{ProteinSequence, ScoringMatrix};
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The score of the best local alignment, and the parts of each sequence it
+/// aligns.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct LocalAlignment {
+    pub score: i32,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for LocalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}\n{}", self.score, self.first, self.second)
+    }
+}
+
+pub struct LocalAlignmentWithAffineGapPenalty;
+
+impl LocalAlignmentWithAffineGapPenalty {
+    /// BLOSUM62, opening gaps for 11 and extending them for 1.
+    fn aligner() -> Aligner<&'static ScoringMatrix> {
+        Aligner::new(
+            Mode::Local,
+            ScoringMatrix::blosum62(),
+            GapPenalty::affine(11, 1),
+        )
+    }
+}
+
+impl Problem for LocalAlignmentWithAffineGapPenalty {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = LocalAlignment;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_8
+PLEASANTLY
+>Rosalind_18
+MEANLY",
+        output: "12
+LEAS
+MEAN",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: ProteinSequence = input[0].1.parse()?;
+        let second: ProteinSequence = input[1].1.parse()?;
+        let alignment = Self::aligner().align(&first, &second);
+
+        Ok(LocalAlignment {
+            score: alignment.score,
+            first: first[alignment.first_range].to_string(),
+            second: second[alignment.second_range].to_string(),
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: ProteinSequence = florist_gen::sequence(rng, size.min(10_000));
+        let second = florist_gen::local_relative(rng, &first);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<LocalAlignmentWithAffineGapPenalty>();
+    }
+
+    #[test]
+    fn substrings_score_as_reported() {
+        let sample = LocalAlignmentWithAffineGapPenalty::SAMPLE.unwrap();
+        let input = LocalAlignmentWithAffineGapPenalty::input_from(sample.input).unwrap();
+        let output = LocalAlignmentWithAffineGapPenalty::solve(input).unwrap();
+
+        // Rosalind accepts any substrings with an alignment as good
+        let first = ProteinSequence::from_str(&output.first).unwrap();
+        let second = ProteinSequence::from_str(&output.second).unwrap();
+        let mut global = LocalAlignmentWithAffineGapPenalty::aligner();
+        global.mode = Mode::Global;
+        assert_eq!(global.align(&first, &second).score, output.score);
+    }
+}
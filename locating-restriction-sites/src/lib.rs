@@ -6,7 +6,9 @@ use std::{
 
 use florist_core::DNASequence;
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::{Serialize, Serializer};
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Output(Vec<(usize, usize)>);
@@ -36,6 +38,23 @@ impl Display for Output {
     }
 }
 
+/// Serializes as a list of sites, each with its (1-based) position and length.
+impl Serialize for Output {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Site {
+            position: usize,
+            length: usize,
+        }
+
+        serializer.collect_seq(
+            self.0
+                .iter()
+                .map(|&(position, length)| Site { position, length }),
+        )
+    }
+}
+
 pub struct LocatingRestrictionSites;
 
 impl Problem for LocatingRestrictionSites {
@@ -43,6 +62,21 @@ impl Problem for LocatingRestrictionSites {
     type Input = MultiFasta;
     type Output = Output;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_24
+TCAATGCATGCGGGTCTATATGCAT",
+        output: "4 6
+5 4
+6 6
+7 4
+17 4
+18 4
+20 6
+21 4",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         // we know the input has to have at least one entry for it to have been
         // parsed, so the unwrap is safe.
@@ -70,32 +104,22 @@ impl Problem for LocatingRestrictionSites {
 
         Ok(Output(points))
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        // the solution expects room for the longest palindrome
+        let seq: DNASequence = florist_gen::sequence(rng, size.max(12));
+        Some(florist_gen::fasta(rng, [seq]).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn locating_sites() {
-        let raw = ">Rosalind_24
-TCAATGCATGCGGGTCTATATGCAT";
-        let input = MultiFasta::from_str(raw).unwrap();
-        let mut output = LocatingRestrictionSites::solve(input).unwrap();
-        output.sort();
-
-        let mut expected = Output(vec![
-            (4, 6),
-            (5, 4),
-            (6, 6),
-            (7, 4),
-            (17, 4),
-            (18, 4),
-            (20, 6),
-            (21, 4),
-        ]);
-        expected.sort();
-
-        assert_eq!(output, expected);
+    fn sample() {
+        assert_sample::<LocatingRestrictionSites>();
     }
 }
@@ -4,7 +4,9 @@
 use std::str::FromStr;
 
 use anyhow::bail;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use itertools::Itertools;
+use rand::Rng;
 
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub struct Population {
@@ -73,20 +75,29 @@ impl Problem for MendelsFirstLaw {
     type Input = Population;
     type Output = f64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "2 2 2",
+        output: "0.78333",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         Ok(input.p_offspring_dominant())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let max = size.clamp(1, u16::MAX as usize) as u32;
+        Some((0..3).map(|_| rng.gen_range(1..=max)).join(" "))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn offspring_dominant() {
-        let raw = "2 2 2";
-        let input = Population::from_str(raw).unwrap();
-        let output = MendelsFirstLaw::solve(input).unwrap();
-        assert!(output - 0.7833333333333333 < f64::EPSILON);
+    fn sample() {
+        assert_sample::<MendelsFirstLaw>();
     }
 }
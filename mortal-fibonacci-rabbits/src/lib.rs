@@ -2,7 +2,8 @@ use std::collections::VecDeque;
 
 use anyhow::bail;
 use florist_inputs::UsizeList;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct MortalFibonacciRabbits;
 
@@ -13,6 +14,11 @@ impl Problem for MortalFibonacciRabbits {
     // you don't get an input that overflows 64 bits.
     type Output = u128;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "6 3",
+        output: "4",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         if input.len() != 2 {
             bail!("Input was not exactly two values: {:?}", input);
@@ -44,6 +50,11 @@ impl Problem for MortalFibonacciRabbits {
 
         Ok(births[0] + births[1] + mature_pop)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let months = size.clamp(1, 100);
+        Some(format!("{} {}", months, rng.gen_range(1..=20)))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::{MatchMismatch, MultipleAligner};
+use florist_core::DNASequence;
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The best sum of pairs score and an alignment achieving it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct ScoredAlignment {
+    pub score: i32,
+    pub rows: Vec<String>,
+}
+
+impl Display for ScoredAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.score)?;
+        for row in &self.rows {
+            write!(f, "\n{}", row)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct MultipleAlignment;
+
+impl Problem for MultipleAlignment {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = ScoredAlignment;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_7
+ATATCCG
+>Rosalind_35
+TCCG
+>Rosalind_23
+ATGTACTG
+>Rosalind_44
+ATGTCTG",
+        output: "-18
+ATAT-CCG
+-T---CCG
+ATGTACTG
+ATGT-CTG",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 4 {
+            bail!("Input is not the right number (4) of sequences");
+        }
+
+        let seqs = input
+            .iter()
+            .map(|(_, raw)| raw.parse())
+            .collect::<Result<Vec<DNASequence>, _>>()?;
+        let alignment = aligner().exact(&seqs);
+
+        Ok(ScoredAlignment {
+            score: alignment.score,
+            rows: alignment.rows.iter().map(|row| row.to_string()).collect(),
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: DNASequence = florist_gen::sequence(rng, size.clamp(1, 10));
+        let rate = rng.gen_range(0.0..0.5);
+        let seqs: Vec<DNASequence> = std::iter::once(first.clone())
+            .chain((1..4).map(|_| florist_gen::evolve(rng, &first, rate)))
+            .collect();
+        Some(florist_gen::fasta(rng, seqs).to_string())
+    }
+}
+
+/// A mismatch, or a symbol against a gap, costs one.
+fn aligner() -> MultipleAligner<MatchMismatch> {
+    MultipleAligner::new(MatchMismatch::new(0, -1), 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::GAP;
+    use florist_core::{Dna, Gapped, Sequence};
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<MultipleAlignment>();
+    }
+
+    #[test]
+    fn rows_score_as_reported() {
+        let sample = MultipleAlignment::SAMPLE.unwrap();
+        let input = MultipleAlignment::input_from(sample.input).unwrap();
+        let output = MultipleAlignment::solve(input.clone()).unwrap();
+        for (row, (_, seq)) in output.rows.iter().zip(input.iter()) {
+            assert_eq!(row.replace(GAP, ""), *seq);
+        }
+
+        // Rosalind's alignment is just as good
+        let expected: Vec<Sequence<Gapped<Dna>>> = sample
+            .output
+            .lines()
+            .skip(1)
+            .map(|row| row.parse().unwrap())
+            .collect();
+        assert_eq!(aligner().score_rows(&expected), output.score);
+    }
+}
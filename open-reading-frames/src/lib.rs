@@ -1,8 +1,10 @@
 use std::{collections::HashSet, fmt::Display};
 
-use florist_core::{DNASequence, Error, ProteinSequence};
+use florist_core::{DNASequence, Error, GeneticCode, OrfFinder, ProteinSequence, StartPolicy};
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::{Serialize, Serializer};
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Output(HashSet<ProteinSequence>);
@@ -15,6 +17,15 @@ impl Display for Output {
     }
 }
 
+/// Serializes as a sorted list of proteins, matching the text output.
+impl Serialize for Output {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut output: Vec<_> = self.0.iter().map(|s| s.as_str()).collect();
+        output.sort();
+        output.serialize(serializer)
+    }
+}
+
 pub struct OpenReadingFrames;
 
 impl Problem for OpenReadingFrames {
@@ -22,26 +33,59 @@ impl Problem for OpenReadingFrames {
     type Input = MultiFasta;
     type Output = Output;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_99
+AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG",
+        output: "MLLGSFRLIPKETLIQVAGSSPCNLS
+M
+MGMTPRLGLESLLE
+MTPRLGLESLLE",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
-        let mut values = input.values();
-        let mut output = HashSet::new();
-        // This unwrap is safe because MultiFasta guarantees at least one value.
-        let seq: DNASequence = values.next().unwrap().parse()?;
+        Self::solve_with_code(input, &OrfFinder::default().genetic_code)
+    }
 
-        for frame in seq.open_frames() {
-            if let Ok(protein) = ProteinSequence::try_from(frame) {
-                output.insert(protein);
-            }
-        }
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        // plant a reading frame somewhere in the sequence, possibly on the
+        // other strand, so there is always something to find
+        let coding = florist_gen::coding_sequence(rng, (size / 3).max(1));
+        let flank: DNASequence = florist_gen::sequence(rng, size);
+        let at = rng.gen_range(0..=flank.len());
+        let mut seq =
+            DNASequence::new_unchecked(format!("{}{}{}", &flank[..at], coding, &flank[at..]));
 
-        let comp = seq.reverse_complement();
-        for frame in comp.open_frames() {
-            if let Ok(protein) = ProteinSequence::try_from(frame) {
-                output.insert(protein);
-            }
+        if rng.gen() {
+            seq = seq.reverse_complement();
         }
 
-        Ok(Output(output))
+        Some(florist_gen::fasta(rng, [seq]).to_string())
+    }
+}
+
+impl OpenReadingFrames {
+    /// Finds the frames with the given genetic code, starting them at every
+    /// one of its start codons rather than only at `ATG`.
+    pub fn solve_with_code(input: MultiFasta, code: &GeneticCode) -> Result<Output, Error> {
+        let finder = OrfFinder {
+            genetic_code: *code,
+            starts: StartPolicy::Nested,
+            ..Default::default()
+        };
+
+        let mut values = input.values();
+        // This unwrap is safe because MultiFasta guarantees at least one value.
+        let seq: DNASequence = values.next().unwrap().parse()?;
+
+        Ok(Output(
+            finder
+                .find(&seq)
+                .into_iter()
+                .map(|orf| orf.protein)
+                .collect(),
+        ))
     }
 }
 
@@ -49,19 +93,26 @@ impl Problem for OpenReadingFrames {
 mod tests {
     use std::str::FromStr;
 
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn reading_frames() {
-        let raw = ">Rosalind_99
-AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
-        let input = MultiFasta::from_str(raw).unwrap();
-        let output = OpenReadingFrames::solve(input).unwrap();
-        let expected = "M
-MGMTPRLGLESLLE
-MLLGSFRLIPKETLIQVAGSSPCNLS
-MTPRLGLESLLE";
+    fn sample() {
+        assert_sample::<OpenReadingFrames>();
+    }
+
+    #[test]
+    fn bacterial_reading_frames() {
+        // GTG starts a frame in bacteria, but not in the standard code
+        let input = MultiFasta::from_str(">Rosalind_1\nCCGTGAAATAG").unwrap();
+        assert_eq!(
+            OpenReadingFrames::solve(input.clone()).unwrap().to_string(),
+            ""
+        );
 
-        assert_eq!(output.to_string(), expected.to_string());
+        let code = "11".parse().unwrap();
+        let output = OpenReadingFrames::solve_with_code(input, &code).unwrap();
+        assert_eq!(output.to_string(), "MK");
     }
 }
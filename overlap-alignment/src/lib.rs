@@ -0,0 +1,115 @@
+use std::fmt::Display;
+
+use anyhow::bail;
+use florist_align::{Aligner, GapPenalty, MatchMismatch, Mode};
+use florist_core::DNASequence;
+use florist_fasta::OrderedMultiFasta;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::Serialize;
+
+/// The score of the best overlap alignment and the alignment itself.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct AlignedOverlap {
+    pub score: i32,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for AlignedOverlap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}\n{}", self.score, self.first, self.second)
+    }
+}
+
+pub struct OverlapAlignment;
+
+impl OverlapAlignment {
+    /// Matches score 1, and mismatches and gapped positions -2.
+    fn aligner() -> Aligner<MatchMismatch> {
+        Aligner::new(
+            Mode::Overlap,
+            MatchMismatch::new(1, -2),
+            GapPenalty::linear(2),
+        )
+    }
+}
+
+impl Problem for OverlapAlignment {
+    type Error = anyhow::Error;
+    type Input = OrderedMultiFasta;
+    type Output = AlignedOverlap;
+
+    // any optimal alignment is accepted
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::FirstLines(1);
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_54
+CTAAGGGATTCCGGTAATTAGACAG
+>Rosalind_45
+ATAGACCATATGTCAGTGACTGTGTAA",
+        output: "1
+ATTAGAC-AG
+AT-AGACCAT",
+    });
+
+    fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
+        if input.len() != 2 {
+            bail!("Input is not the right number (2) of sequences");
+        }
+
+        let first: DNASequence = input[0].1.parse()?;
+        let second: DNASequence = input[1].1.parse()?;
+        let alignment = Self::aligner().align(&first, &second);
+
+        Ok(AlignedOverlap {
+            score: alignment.score,
+            first: alignment.first,
+            second: alignment.second,
+        })
+    }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: DNASequence = florist_gen::sequence(rng, size.min(10_000));
+
+        // the second starts with a modified suffix of the first
+        let start = rng.gen_range(0..first.len());
+        let rate = rng.gen_range(0.0..0.3);
+        let overlap =
+            florist_gen::evolve(rng, &first.subsequence_unchecked(start, first.len()), rate);
+        let len = rng.gen_range(0..=first.len());
+        let tail: DNASequence = florist_gen::sequence(rng, len);
+        let second = format!("{}{}", overlap, tail);
+
+        Some(florist_gen::fasta(rng, [first.to_string(), second]).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use florist_align::GAP;
+    use florist_plumbing::assert_sample;
+
+    use super::*;
+
+    #[test]
+    fn sample() {
+        assert_sample::<OverlapAlignment>();
+    }
+
+    #[test]
+    fn rows_score_as_reported() {
+        let sample = OverlapAlignment::SAMPLE.unwrap();
+        let input = OverlapAlignment::input_from(sample.input).unwrap();
+        let output = OverlapAlignment::solve(input.clone()).unwrap();
+
+        // Rosalind accepts any alignment as good
+        assert!(input[0].1.ends_with(&output.first.replace(GAP, "")));
+        assert!(input[1].1.starts_with(&output.second.replace(GAP, "")));
+        let aligner = OverlapAlignment::aligner();
+        assert_eq!(
+            aligner.score_rows(&output.first, &output.second),
+            output.score
+        );
+    }
+}
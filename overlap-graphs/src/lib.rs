@@ -1,8 +1,10 @@
 use std::{fmt::Display, ops::Deref};
 
-use florist_core::Error;
+use florist_core::{Dna, Error};
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{OutputOrder, Problem, Sample};
+use rand::Rng;
+use serde::{Serialize, Serializer};
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Graph(Vec<(String, String)>);
@@ -35,6 +37,20 @@ impl Display for Graph {
     }
 }
 
+/// Serializes as a list of edges, each with the description of the sequence it
+/// goes from and the one it goes to.
+impl Serialize for Graph {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Edge<'a> {
+            from: &'a str,
+            to: &'a str,
+        }
+
+        serializer.collect_seq(self.iter().map(|(from, to)| Edge { from, to }))
+    }
+}
+
 pub struct OverlapGraphs;
 
 impl Problem for OverlapGraphs {
@@ -42,6 +58,24 @@ impl Problem for OverlapGraphs {
     type Input = MultiFasta;
     type Output = Graph;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_0498
+AAATAAA
+>Rosalind_2391
+AAATTTT
+>Rosalind_2323
+TTTTCCC
+>Rosalind_0442
+AAATCCC
+>Rosalind_5013
+GGGTGGG",
+        output: "Rosalind_0498 Rosalind_2391
+Rosalind_0498 Rosalind_0442
+Rosalind_2391 Rosalind_2323",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut graph = Graph::default();
 
@@ -66,6 +100,17 @@ impl Problem for OverlapGraphs {
 
         Ok(graph)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let count = rng.gen_range(1..=50);
+        let mut sequences = Vec::with_capacity(count);
+        for _ in 0..count {
+            let len = rng.gen_range(1..=size.max(1));
+            sequences.push(florist_gen::sequence::<Dna, _>(rng, len));
+        }
+
+        Some(florist_gen::fasta(rng, sequences).to_string())
+    }
 }
 
 pub struct SortedOverlapGraphs;
@@ -75,6 +120,14 @@ impl Problem for SortedOverlapGraphs {
     type Input = MultiFasta;
     type Output = Graph;
 
+    const OUTPUT_ORDER: OutputOrder = OutputOrder::Unordered;
+
+    const SAMPLE: Option<Sample> = <OverlapGraphs as Problem>::SAMPLE;
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        OverlapGraphs::generate(rng, size)
+    }
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut graph = Graph::default();
 
@@ -109,30 +162,29 @@ impl Problem for SortedOverlapGraphs {
 mod tests {
     use std::str::FromStr;
 
+    use florist_plumbing::{assert_sample, generate_seeded};
+
     use super::*;
 
     #[test]
-    fn overlaps() {
-        let raw = ">Rosalind_0498
-AAATAAA
->Rosalind_2391
-AAATTTT
->Rosalind_2323
-TTTTCCC
->Rosalind_0442
-AAATCCC
->Rosalind_5013
-GGGTGGG";
-        let input = MultiFasta::from_str(raw).unwrap();
-        let mut output = OverlapGraphs::solve(input).unwrap();
-        output.sort();
-
-        let mut expected = Graph::default();
-        expected.edge("Rosalind_0498".to_string(), "Rosalind_2391".to_string());
-        expected.edge("Rosalind_0498".to_string(), "Rosalind_0442".to_string());
-        expected.edge("Rosalind_2391".to_string(), "Rosalind_2323".to_string());
-        expected.sort();
-
-        assert_eq!(output, expected);
+    fn sample() {
+        assert_sample::<OverlapGraphs>();
+    }
+
+    #[test]
+    fn sorted_agrees_with_unsorted() {
+        for seed in 0..50 {
+            for size in [1, 3, 5, 20] {
+                let raw = generate_seeded::<OverlapGraphs>(seed, size).unwrap();
+
+                let mut unsorted =
+                    OverlapGraphs::solve(MultiFasta::from_str(&raw).unwrap()).unwrap();
+                unsorted.sort();
+                let sorted =
+                    SortedOverlapGraphs::solve(MultiFasta::from_str(&raw).unwrap()).unwrap();
+
+                assert_eq!(unsorted, sorted, "seed {}, size {}", seed, size);
+            }
+        }
     }
 }
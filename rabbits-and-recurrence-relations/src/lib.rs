@@ -1,6 +1,7 @@
 use std::{num::ParseIntError, str::FromStr};
 
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -44,6 +45,11 @@ impl Problem for RabbitsAndRecurrenceRelations {
     type Input = Recurrence;
     type Output = usize;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "5 3",
+        output: "19",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut prev = 1_usize;
         let mut cur = 1_usize;
@@ -56,16 +62,22 @@ impl Problem for RabbitsAndRecurrenceRelations {
 
         Ok(cur)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        // the answer would overflow past these limits
+        let months = size.clamp(1, 40);
+        Some(format!("{} {}", months, rng.gen_range(1..=5)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn growth() {
-        let input = Recurrence::from_str("5 3").unwrap();
-        let output = RabbitsAndRecurrenceRelations::solve(input).unwrap();
-        assert_eq!(output, 19);
+    fn sample() {
+        assert_sample::<RabbitsAndRecurrenceRelations>();
     }
 }
@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 
-use florist_core::{DNASequence, ProteinSequence};
+use florist_core::{DNASequence, Dna, ProteinSequence};
 use florist_fasta::OrderedMultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::{seq::index, Rng};
 
 pub struct RnaSplicing;
 
@@ -11,6 +12,16 @@ impl Problem for RnaSplicing {
     type Input = OrderedMultiFasta;
     type Output = ProteinSequence;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_10
+ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
+>Rosalind_12
+ATCGGTCGAA
+>Rosalind_15
+ATCGGTCGAGCGTGT",
+        output: "MVYIADKQHVASREAYGHMFKVCA",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         let mut values = input.iter().map(|(_, v)| v);
         // This unwrap is safe because MultiFasta guarantees at least one.
@@ -24,27 +35,42 @@ impl Problem for RnaSplicing {
 
         Ok(seq.try_into()?)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let coding = florist_gen::coding_sequence(rng, size);
+        let mut introns = Vec::new();
+        for _ in 0..rng.gen_range(0..=5) {
+            // long enough to be very unlikely to also appear in an exon
+            let len = rng.gen_range(20..=40);
+            introns.push(florist_gen::sequence::<Dna, _>(rng, len).to_string());
+        }
+
+        // splice the introns in at distinct points of the coding sequence,
+        // so removing them gives back exactly the coding sequence
+        let mut points = index::sample(rng, coding.len() + 1, introns.len()).into_vec();
+        points.sort_unstable();
+
+        let mut raw = String::new();
+        let mut last = 0;
+        for (point, intron) in points.into_iter().zip(&introns) {
+            raw.push_str(&coding[last..point]);
+            raw.push_str(intron);
+            last = point;
+        }
+        raw.push_str(&coding[last..]);
+
+        Some(florist_gen::fasta(rng, std::iter::once(raw).chain(introns)).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn rns_splicing() {
-        let raw = ">Rosalind_10
-ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG
->Rosalind_12
-ATCGGTCGAA
->Rosalind_15
-ATCGGTCGAGCGTGT";
-        let input = OrderedMultiFasta::from_str(raw).unwrap();
-        let output = RnaSplicing::solve(input).unwrap();
-        assert_eq!(
-            output,
-            ProteinSequence::from_str("MVYIADKQHVASREAYGHMFKVCA").unwrap()
-        );
+    fn sample() {
+        assert_sample::<RnaSplicing>();
     }
 }
@@ -1,5 +1,6 @@
 use florist_core::Error;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct {{project-name|upper_camel_case}};
 
@@ -8,22 +9,28 @@ impl Problem for {{project-name|upper_camel_case}} {
     type Input = usize;
     type Output = usize;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "10",
+        output: "10",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         Ok(input)
     }
+
+    fn generate<R: Rng + ?Sized>(_rng: &mut R, size: usize) -> Option<String> {
+        Some(size.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn example() {
-        let raw = "10";
-        let input = usize::from_str(raw).expect("Failed to parse input");
-        let output = {{project-name|upper_camel_case}}::solve(input).expect("Failed to solve");
-        assert_eq!(output, 10);
+    fn sample() {
+        assert_sample::<{{project-name|upper_camel_case}}>();
     }
 }
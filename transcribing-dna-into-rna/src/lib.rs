@@ -1,5 +1,6 @@
-use florist_core::{sequence::RNASequence, DNASequence, Error};
-use florist_plumbing::Problem;
+use florist_core::{sequence::RNASequence, DNASequence, Dna, Error};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct TranscribingDnaIntoRna;
 
@@ -8,23 +9,28 @@ impl Problem for TranscribingDnaIntoRna {
     type Input = DNASequence;
     type Output = RNASequence;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "GATGGAACTTGACTACGTAAATT",
+        output: "GAUGGAACUUGACUACGUAAAUU",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         Ok(input.into())
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        Some(florist_gen::sequence::<Dna, _>(rng, size).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use florist_plumbing::assert_sample;
+
     use super::*;
-    use std::str::FromStr;
 
     #[test]
-    fn conversion() {
-        let input = DNASequence::from_str("GATGGAACTTGACTACGTAAATT").expect("Failed to parse");
-        let output = TranscribingDnaIntoRna::solve(input).expect("Failed to solve");
-        assert_eq!(
-            output,
-            RNASequence::from_str("GAUGGAACUUGACUACGUAAAUU").unwrap()
-        );
+    fn sample() {
+        assert_sample::<TranscribingDnaIntoRna>();
     }
 }
@@ -1,7 +1,8 @@
 use anyhow::bail;
 use florist_core::{DNASequence, Substitutable};
 use florist_fasta::MultiFasta;
-use florist_plumbing::Problem;
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct TransitionsAndTransversions;
 
@@ -10,6 +11,16 @@ impl Problem for TransitionsAndTransversions {
     type Input = MultiFasta;
     type Output = f64;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: ">Rosalind_0209
+GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGA
+AGTACGGGCATCAACCCAGTT
+>Rosalind_2200
+TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGC
+GGTACGAGTGTTCCTTTGGGT",
+        output: "1.21428571429",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         if input.len() != 2 {
             bail!("Wrong number of sequences in input");
@@ -22,24 +33,23 @@ impl Problem for TransitionsAndTransversions {
 
         Ok(sequences[0].transition_transversion_ratio(&sequences[1])?)
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let first: DNASequence = florist_gen::sequence(rng, size);
+        let rate = rng.gen();
+        let second = florist_gen::mutate(rng, &first, rate);
+        Some(florist_gen::fasta(rng, [first, second]).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use florist_plumbing::assert_sample;
 
     use super::*;
 
     #[test]
-    fn finding_ratio() {
-        let raw = ">Rosalind_0209
-GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGA
-AGTACGGGCATCAACCCAGTT
->Rosalind_2200
-TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGC
-GGTACGAGTGTTCCTTTGGGT";
-        let input = MultiFasta::from_str(raw).unwrap();
-        let output = TransitionsAndTransversions::solve(input).unwrap();
-        assert!((output - 1.2142857142857142).abs() < f64::EPSILON);
+    fn sample() {
+        assert_sample::<TransitionsAndTransversions>();
     }
 }
@@ -1,5 +1,6 @@
-use florist_core::{sequence::ProteinSequence, Error, RNASequence};
-use florist_plumbing::Problem;
+use florist_core::{sequence::ProteinSequence, Error, GeneticCode, GeneticSequence, RNASequence};
+use florist_plumbing::{Problem, Sample};
+use rand::Rng;
 
 pub struct TranslatingRnaIntoProtein;
 
@@ -8,25 +9,50 @@ impl Problem for TranslatingRnaIntoProtein {
     type Input = RNASequence;
     type Output = ProteinSequence;
 
+    const SAMPLE: Option<Sample> = Some(Sample {
+        input: "AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA",
+        output: "MAMAPRTEINSTRING",
+    });
+
     fn solve(input: Self::Input) -> Result<Self::Output, Self::Error> {
         input.try_into()
     }
+
+    fn generate<R: Rng + ?Sized>(rng: &mut R, size: usize) -> Option<String> {
+        let seq = florist_gen::coding_sequence(rng, size);
+        Some(RNASequence::from(seq).to_string())
+    }
+}
+
+impl TranslatingRnaIntoProtein {
+    /// Translates with the given genetic code instead of the standard one,
+    /// starting at any of its start codons.
+    pub fn solve_with_code(
+        input: RNASequence,
+        code: &GeneticCode,
+    ) -> Result<ProteinSequence, Error> {
+        input.to_protein_with(code)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use florist_plumbing::assert_sample;
+
     use super::*;
 
     #[test]
-    fn rna_to_protein() {
-        let raw = "AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA";
-        let input = RNASequence::from_str(raw).unwrap();
-        let output = TranslatingRnaIntoProtein::solve(input).unwrap();
-        assert_eq!(
-            output,
-            ProteinSequence::from_str("MAMAPRTEINSTRING").unwrap()
-        );
+    fn sample() {
+        assert_sample::<TranslatingRnaIntoProtein>();
+    }
+
+    #[test]
+    fn mitochondrial_rna_to_protein() {
+        let input = RNASequence::from_str("AUGUGAAUAAGA").unwrap();
+        let code = "2".parse().unwrap();
+        let output = TranslatingRnaIntoProtein::solve_with_code(input, &code).unwrap();
+        assert_eq!(output, ProteinSequence::from_str("MWM").unwrap());
     }
 }